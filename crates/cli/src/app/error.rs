@@ -6,7 +6,7 @@ use std::fmt;
 use std::io::Write;
 
 pub fn show<OD: OsDirs, O: Write, E: Write>(shell: &mut Shell<OD, O, E>, err: &Error) {
-    let rf = ErrorRender::new(err);
+    let rf = ErrorRender::new(err, shell.err_colors());
     let res = shell.err(rf);
     match res {
         Ok(_) => {}
@@ -32,6 +32,16 @@ impl fmt::Display for Error {
             Error::BadHeaderValue(_) => write!(f, "invalid header value."),
             Error::Request(err) => write!(f, "{}", err),
             Error::Io(err) => write!(f, "{}", err),
+            Error::Filter(err) => write!(f, "invalid filter, {}.", err),
+            Error::Theme(err) => write!(f, "the theme {}.", err),
+            Error::FromCurl(err) => {
+                writeln!(f, "the {} subcommand failed, {}", crate::commands::curl::COMMAND_FROM_CURL, err)?;
+                write!(f, "try '{} {} --help' for more information.", rh_name!(), crate::commands::curl::COMMAND_FROM_CURL)
+            }
+            Error::Har(err) => {
+                writeln!(f, "the {} subcommand failed, {}", crate::commands::har::COMMAND_HAR, err)?;
+                write!(f, "try '{} {} --help' for more information.", rh_name!(), crate::commands::har::COMMAND_HAR)
+            }
             #[cfg(feature = "alias")]
             Error::AliasCommand(err) => {
                 writeln!(f, "the alias subcommand failed, {}", err)?;
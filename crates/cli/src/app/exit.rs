@@ -14,6 +14,10 @@ pub fn code_on_error(err: Error) -> i32 {
         Error::ItemsAndRawMix => 200,
         Error::TooManyRaw => 201,
         Error::ContradictoryScheme => 301,
+        Error::Filter(_) => 302,
+        Error::Theme(_) => 303,
+        Error::FromCurl(_) => 960,
+        Error::Har(_) => 970,
         #[cfg(feature = "alias")]
         Error::AliasCommand(_) => 950,
         #[cfg(feature = "alias")]
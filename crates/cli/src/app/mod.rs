@@ -5,6 +5,7 @@ mod exit;
 
 use crate::commands::ArgsCommand;
 use crate::core::{Args, Error, Result};
+use crate::parser;
 use crate::shell::os::OsDirs;
 use crate::shell::Shell;
 use std::io::Write;
@@ -41,7 +42,10 @@ impl<'a, OD: OsDirs, O: Write, E: Write> App<'a, OD, O, E> {
     }
 
     pub fn run(&mut self, args: &mut Args) -> Result<()> {
+        // Checked again once an alias is expanded, its flags can turn the colours off too
+        self.disable_colors_if_asked(args);
         let command = args.command(self.shell.os_dirs())?;
+        self.disable_colors_if_asked(args);
         command.execute(self.shell, args, || {})?;
 
         #[cfg(feature = "spinner")]
@@ -50,4 +54,11 @@ impl<'a, OD: OsDirs, O: Write, E: Write> App<'a, OD, O, E> {
         // self.shell.flush()?;
         Ok(())
     }
+
+    // The errors can happen before a workspace is built, e.g. an invalid flag
+    fn disable_colors_if_asked(&mut self, args: &Args) {
+        if parser::no_color(args) {
+            self.shell.disable_err_colors();
+        }
+    }
 }
@@ -29,7 +29,7 @@ pub fn get(args: &mut Args, is_valid_alias: IsValidAliasPtr, fix_alias: FixAlias
         }
     }
 
-    if args.is_empty() || (args.len() == 1 && is_valid_alias(args.get(0))) {
+    if args.is_empty() || (args.len() == 1 && is_valid_alias(args.first())) {
         Err(Error::NoArgs)
     } else {
         Ok(Action::Add)
@@ -165,7 +165,7 @@ mod tests {
             use super::*;
 
             fn test_default_alias_with<OD: OsDirs>(os_dirs: OD, url: &str) -> Result<Args> {
-                test_alias_with(os_dirs, DEFAULT_ALIAS_NAME, rh_test::args!["alias", url.clone()])
+                test_alias_with(os_dirs, DEFAULT_ALIAS_NAME, rh_test::args!["alias", url])
             }
             #[test]
             fn default_alias() {
@@ -197,7 +197,7 @@ mod tests {
             use super::*;
 
             fn test_custom_alias<OD: OsDirs>(os_dirs: OD, url: &str) -> Result<Args> {
-                test_alias_with(os_dirs, CUSTOM_ALIAS_NAME_1, rh_test::args!["alias", rh_test::arg_alias!(CUSTOM_ALIAS_NAME_1), url.clone()])
+                test_alias_with(os_dirs, CUSTOM_ALIAS_NAME_1, rh_test::args!["alias", rh_test::arg_alias!(CUSTOM_ALIAS_NAME_1), url])
             }
 
             #[test]
@@ -22,7 +22,7 @@ pub fn from_name<OD: OsDirs>(os_dirs: &OD, name: &str) -> Result<Args> {
 }
 
 pub fn from_path(path: &Path) -> std::result::Result<Args, io::Error> {
-    match File::open(&path) {
+    match File::open(path) {
         Ok(file) => from_reader(&file),
         Err(err) => Err(err),
     }
@@ -30,10 +30,7 @@ pub fn from_path(path: &Path) -> std::result::Result<Args, io::Error> {
 
 fn from_reader<R: io::Read>(reader: R) -> std::result::Result<Args, io::Error> {
     let buffer = BufReader::new(reader);
-    match buffer.lines().filter_ok(|arg| !arg.is_empty()).collect() {
-        Ok(args) => Ok(args),
-        Err(err) => Err(err),
-    }
+    buffer.lines().filter_ok(|arg| !arg.is_empty()).collect()
 }
 
 // UNIT TESTS /////////////////////////////////////////////////////////////////////////////
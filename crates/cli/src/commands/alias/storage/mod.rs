@@ -4,7 +4,9 @@ pub(crate) mod show;
 pub(crate) mod store;
 
 use crate::core::Result;
+#[allow(unused_imports)]
 pub use load::from_default;
+#[allow(unused_imports)]
 pub use load::from_name;
 
 pub const DEFAULT_ALIAS_NAME: &str = "default";
@@ -12,6 +14,7 @@ pub const DEFAULT_ALIAS_NAME: &str = "default";
 pub const ALIAS_FILENAME_PREFIX: &str = ".rh_";
 pub const ALIAS_FILENAME_SUFFIX: &str = "_rc";
 
+#[allow(dead_code)]
 pub trait AliasArgItem {
     fn enrich_with_alias(&mut self) -> Result<()>;
 }
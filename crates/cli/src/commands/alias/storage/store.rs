@@ -59,7 +59,7 @@ fn create_alias_file(name: &str, path: &Path) -> Result<File> {
             if !Path::exists(dir) && fs::create_dir(dir).is_err() {
                 return Err(Error::CannotCreateAlias(name.to_string(), ErrorKind::CannotCreateAppConfigDirectory));
             }
-            let file = File::create(&path)?;
+            let file = File::create(path)?;
             Ok(file)
         }
         None => Err(Error::CannotCreateAlias(name.to_string(), ErrorKind::CannotCreateAppConfigDirectory)),
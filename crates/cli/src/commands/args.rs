@@ -3,7 +3,12 @@ use super::{
     alias::{from_default, from_name, AliasCommand, COMMAND_ALIAS},
     ALIAS_NAME_PREFIX,
 };
-use super::{http::HttpCommand, ArgsCommand, Command, Result};
+use super::{
+    curl::{FromCurlCommand, COMMAND_FROM_CURL},
+    har::{HarCommand, COMMAND_HAR},
+    http::HttpCommand,
+    ArgsCommand, Command, Result,
+};
 use crate::{
     core::{Args, Error},
     shell::os::OsDirs,
@@ -12,6 +17,12 @@ use std::io::Write;
 
 impl<OD: OsDirs, O: Write, E: Write> ArgsCommand<OD, O, E> for Args {
     fn command(&mut self, os_dirs: &OD) -> Result<Box<dyn Command<OD, O, E>>> {
+        if self.first().map(String::as_str) == Some(COMMAND_FROM_CURL) {
+            return Ok(Box::new(FromCurlCommand {}));
+        }
+        if self.first().map(String::as_str) == Some(COMMAND_HAR) {
+            return Ok(Box::new(HarCommand {}));
+        }
         #[cfg(feature = "alias")]
         match self.first() {
             Some(first) => {
@@ -37,6 +48,7 @@ impl<OD: OsDirs, O: Write, E: Write> ArgsCommand<OD, O, E> for Args {
         }
         #[cfg(not(feature = "alias"))]
         {
+            let _ = os_dirs;
             if !self.is_empty() {
                 Ok(Box::new(HttpCommand {}))
             } else {
@@ -46,13 +58,15 @@ impl<OD: OsDirs, O: Write, E: Write> ArgsCommand<OD, O, E> for Args {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alias"))]
 mod tests {
     use super::*;
     use crate::commands::alias::storage::DEFAULT_ALIAS_NAME;
     use crate::test::alias::*;
     use crate::test::os::TestValidOsDirs;
 
+    type TestCommand = Box<dyn Command<TestValidOsDirs, &'static mut Vec<u8>, &'static mut Vec<u8>>>;
+
     #[test]
     fn default_alias() {
         setup();
@@ -104,7 +118,7 @@ mod tests {
     #[test]
     fn error_config() {
         let mut args = rh_test::args![rh_test::arg_alias!("error"), "-cuh", "http://test.com"];
-        let res: Result<Box<dyn Command<TestValidOsDirs, &mut Vec<u8>, &mut Vec<u8>>>> = args.command(&TestValidOsDirs::new());
+        let res: Result<TestCommand> = args.command(&TestValidOsDirs::new());
         assert!(res.is_err());
         // FIXME Checks the error
     }
@@ -0,0 +1,429 @@
+use super::{Error, Result};
+use crate::core::Args;
+use crate::parser::core::{ArgDetection, CAFILE_FLAG, RAW_FLAG, RAW_HEADER_FLAG};
+use crate::request::header::{ACCEPT, CONTENT_TYPE};
+use std::fs;
+use std::slice::Iter;
+use url::form_urlencoded;
+
+const CURL: &str = "curl";
+const FORM_CONTENT_TYPE: &str = "application/x-www-form-urlencoded";
+const JSON_CONTENT_TYPE: &str = "application/json";
+const INSECURE_FLAG: &str = "--insecure";
+
+const IGNORED_OPTIONS: &[&str] = &[
+    "compressed",
+    "fail",
+    "fail-with-body",
+    "globoff",
+    "http1.1",
+    "http2",
+    "http2-prior-knowledge",
+    "include",
+    "location",
+    "no-buffer",
+    "no-progress-meter",
+    "path-as-is",
+    "progress-bar",
+    "raw",
+    "remote-header-name",
+    "remote-name",
+    "show-error",
+    "silent",
+    "verbose",
+];
+const IGNORED_OPTIONS_WITH_VALUE: &[&str] = &[
+    "connect-timeout",
+    "cookie-jar",
+    "dump-header",
+    "limit-rate",
+    "max-redirs",
+    "max-time",
+    "output",
+    "retry",
+    "retry-delay",
+    "retry-max-time",
+    "stderr",
+    "trace",
+    "write-out",
+];
+const OPTIONS_WITH_VALUE: &[&str] = &[
+    "cacert",
+    "cookie",
+    "data",
+    "data-ascii",
+    "data-binary",
+    "data-raw",
+    "data-urlencode",
+    "form",
+    "header",
+    "json",
+    "proxy",
+    "range",
+    "referer",
+    "request",
+    "upload-file",
+    "url",
+    "user",
+    "user-agent",
+];
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(Default)]
+struct Curl {
+    method: Option<String>,
+    url: Option<String>,
+    headers: Vec<(String, String)>,
+    data: Vec<String>,
+    content_type: Option<&'static str>,
+    accept: Option<&'static str>,
+    get: bool,
+    insecure: bool,
+    cafile: Option<String>,
+}
+
+pub fn to_args(words: &[String]) -> Result<Args> {
+    let mut words = words.iter();
+    let mut curl = Curl::default();
+    if let Some(first) = words.as_slice().first() {
+        if first == CURL || first.ends_with("/curl") || first.ends_with("curl.exe") {
+            words.next();
+        }
+    }
+    if words.as_slice().is_empty() {
+        return Err(Error::NoCommand);
+    }
+
+    while let Some(word) = words.next() {
+        if let Some(long) = word.strip_prefix("--") {
+            let value = if takes_value(long) { Some(next_value(&mut words, word)?) } else { None };
+            curl.apply(long, value)?;
+        } else if word.len() > 1 && word.starts_with('-') {
+            let shorts = &word[1..];
+            for (pos, c) in shorts.char_indices() {
+                let long = long_option(c).ok_or_else(|| Error::UnsupportedOption(format!("-{}", c)))?;
+                if takes_value(long) {
+                    let attached = &shorts[pos + c.len_utf8()..];
+                    let value = if attached.is_empty() { next_value(&mut words, word)? } else { attached.to_string() };
+                    curl.apply(long, Some(value))?;
+                    break;
+                }
+                curl.apply(long, None)?;
+            }
+        } else {
+            curl.set_url(word)?;
+        }
+    }
+    curl.into_args()
+}
+
+impl Curl {
+    fn apply(&mut self, option: &str, value: Option<String>) -> Result<()> {
+        if IGNORED_OPTIONS.contains(&option) || IGNORED_OPTIONS_WITH_VALUE.contains(&option) {
+            return Ok(());
+        }
+        let value = value.unwrap_or_default();
+        match option {
+            "request" => self.method = Some(value),
+            "head" => self.method = Some("HEAD".into()),
+            "get" => self.get = true,
+            "url" => self.set_url(&value)?,
+            "header" => self.push_header(&value),
+            "user-agent" => self.headers.push(("User-Agent".into(), value)),
+            "referer" => self.headers.push(("Referer".into(), value)),
+            "range" => self.headers.push(("Range".into(), format!("bytes={}", value))),
+            "user" => self.headers.push(("Authorization".into(), format!("Basic {}", base64::encode(value)))),
+            "cookie" => {
+                if !value.contains('=') {
+                    return Err(Error::UnsupportedOption(format!("--{} <file>", option)));
+                }
+                self.headers.push(("Cookie".into(), value));
+            }
+            "cacert" => self.cafile = Some(value),
+            "insecure" => self.insecure = true,
+            "data" | "data-ascii" => self.push_data(read_data(&value, true)?, FORM_CONTENT_TYPE),
+            "data-binary" => self.push_data(read_data(&value, false)?, FORM_CONTENT_TYPE),
+            "data-raw" => self.push_data(value, FORM_CONTENT_TYPE),
+            "data-urlencode" => self.push_data(url_encode(&value)?, FORM_CONTENT_TYPE),
+            "json" => {
+                self.push_data(read_data(&value, false)?, JSON_CONTENT_TYPE);
+                self.accept = Some(JSON_CONTENT_TYPE);
+            }
+            _ => return Err(Error::UnsupportedOption(format!("--{}", option))),
+        }
+        Ok(())
+    }
+
+    fn set_url(&mut self, url: &str) -> Result<()> {
+        if self.url.is_some() {
+            return Err(Error::UnsupportedOption(format!("{} (only one URL is allowed)", url)));
+        }
+        self.url = Some(url.to_string());
+        Ok(())
+    }
+
+    fn push_header(&mut self, header: &str) {
+        if let Some((name, value)) = header.split_once(':') {
+            let value = value.trim();
+            // "Name:" removes a header in curl, there is nothing to send in that case
+            if !value.is_empty() {
+                self.headers.push((name.trim().to_string(), value.to_string()));
+            }
+        }
+    }
+
+    fn push_data(&mut self, data: String, content_type: &'static str) {
+        self.data.push(data);
+        if self.content_type.is_none() {
+            self.content_type = Some(content_type);
+        }
+    }
+
+    fn has_header(&self, name: &str) -> bool {
+        self.headers.iter().any(|(key, _)| key.eq_ignore_ascii_case(name))
+    }
+
+    fn into_args(mut self) -> Result<Args> {
+        let mut url = self.url.take().ok_or(Error::MissingUrl)?;
+        let mut body = None;
+        if !self.data.is_empty() {
+            let data = self.data.join("&");
+            if self.get {
+                url.push(if url.contains('?') { '&' } else { '?' });
+                url.push_str(&data);
+            } else {
+                body = Some(data);
+            }
+        }
+
+        let mut args = Args::new();
+        let implicit_method = if body.is_some() { "POST" } else { "GET" };
+        let method = if self.get && self.method.is_none() {
+            Some("GET".to_string())
+        } else {
+            self.method.take()
+        };
+        if let Some(method) = method.filter(|method| method != implicit_method) {
+            args.push(method);
+        }
+        args.push(url);
+
+        if body.is_some() {
+            if let Some(content_type) = self.content_type.filter(|_| !self.has_header(CONTENT_TYPE)) {
+                self.headers.push(("Content-Type".into(), content_type.into()));
+            }
+            if let Some(accept) = self.accept.filter(|_| !self.has_header(ACCEPT)) {
+                self.headers.push(("Accept".into(), accept.into()));
+            }
+        }
+        for (name, value) in self.headers.iter() {
+            args.push(header_arg(name, value));
+        }
+        if let Some(body) = body {
+            args.push(format!("{}{}", RAW_FLAG, body));
+        }
+        if let Some(cafile) = self.cafile {
+            args.push(format!("{}{}", CAFILE_FLAG, cafile));
+        }
+        if self.insecure {
+            args.push(INSECURE_FLAG.into());
+        }
+        Ok(args)
+    }
+}
+
+fn header_arg(name: &str, value: &str) -> String {
+    let arg = format!("{}:{}", name, value);
+    if arg.is_header() && !arg.is_url() && !arg.is_very_likely_url() {
+        arg
+    } else {
+        format!("{}{}", RAW_HEADER_FLAG, arg)
+    }
+}
+
+fn next_value(words: &mut Iter<String>, option: &str) -> Result<String> {
+    words.next().cloned().ok_or_else(|| Error::MissingValue(option.to_string()))
+}
+
+fn takes_value(option: &str) -> bool {
+    OPTIONS_WITH_VALUE.contains(&option) || IGNORED_OPTIONS_WITH_VALUE.contains(&option)
+}
+
+fn long_option(short: char) -> Option<&'static str> {
+    let long = match short {
+        'A' => "user-agent",
+        'b' => "cookie",
+        'c' => "cookie-jar",
+        'D' => "dump-header",
+        'd' => "data",
+        'e' => "referer",
+        'F' => "form",
+        'f' => "fail",
+        'G' => "get",
+        'g' => "globoff",
+        'H' => "header",
+        'I' => "head",
+        'i' => "include",
+        'J' => "remote-header-name",
+        'k' => "insecure",
+        'L' => "location",
+        'm' => "max-time",
+        'N' => "no-buffer",
+        'O' => "remote-name",
+        'o' => "output",
+        'r' => "range",
+        'S' => "show-error",
+        's' => "silent",
+        'T' => "upload-file",
+        'u' => "user",
+        'v' => "verbose",
+        'w' => "write-out",
+        'X' => "request",
+        'x' => "proxy",
+        '#' => "progress-bar",
+        _ => return None,
+    };
+    Some(long)
+}
+
+fn read_data(value: &str, strip_newlines: bool) -> Result<String> {
+    match value.strip_prefix('@') {
+        Some(path) => {
+            let content = fs::read_to_string(path)?;
+            if strip_newlines {
+                Ok(content.replace(&['\r', '\n'][..], ""))
+            } else {
+                Ok(content)
+            }
+        }
+        None => Ok(value.to_string()),
+    }
+}
+
+fn url_encode(value: &str) -> Result<String> {
+    let encode = |content: &str| form_urlencoded::byte_serialize(content.as_bytes()).collect::<String>();
+    if let Some(path) = value.strip_prefix('@') {
+        return Ok(encode(&fs::read_to_string(path)?));
+    }
+    match value.split_once('=') {
+        Some(("", content)) => Ok(encode(content)),
+        Some((name, content)) => Ok(format!("{}={}", name, encode(content))),
+        None => Ok(encode(value)),
+    }
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{to_args, Error};
+
+    macro_rules! assert_args_eq {
+        ($words:expr, $expected:expr) => {
+            let words: Vec<String> = $words.iter().map(|word| word.to_string()).collect();
+            assert_eq!(to_args(&words).unwrap(), $expected)
+        };
+    }
+
+    macro_rules! assert_error_eq {
+        ($words:expr, $expected:expr) => {
+            let words: Vec<String> = $words.iter().map(|word| word.to_string()).collect();
+            assert_eq!(to_args(&words).unwrap_err(), $expected)
+        };
+    }
+
+    #[test]
+    fn url_only() {
+        assert_args_eq!(["curl", "https://test.com"], vec!["https://test.com"]);
+        assert_args_eq!(["https://test.com"], vec!["https://test.com"]);
+        assert_args_eq!(["/usr/bin/curl", "--url", "https://test.com"], vec!["https://test.com"]);
+    }
+
+    #[test]
+    fn method() {
+        assert_args_eq!(["curl", "-X", "PUT", "https://test.com"], vec!["PUT", "https://test.com"]);
+        assert_args_eq!(["curl", "-XDELETE", "https://test.com"], vec!["DELETE", "https://test.com"]);
+        assert_args_eq!(["curl", "--request", "GET", "https://test.com"], vec!["https://test.com"]);
+        assert_args_eq!(["curl", "-I", "https://test.com"], vec!["HEAD", "https://test.com"]);
+    }
+
+    #[test]
+    fn headers() {
+        assert_args_eq!(
+            [
+                "curl",
+                "https://test.com",
+                "-H",
+                "X-Key: value",
+                "-H",
+                "Cookie: a=b; c=d",
+                "-H",
+                "X-Count: 5",
+                "-H",
+                "Accept:"
+            ],
+            vec!["https://test.com", "X-Key:value", "--raw-header=Cookie:a=b; c=d", "--raw-header=X-Count:5"]
+        );
+        assert_args_eq!(
+            ["curl", "-A", "agent", "-e", "https://from.com", "https://test.com"],
+            vec!["https://test.com", "User-Agent:agent", "Referer:https://from.com"]
+        );
+    }
+
+    #[test]
+    fn basic_auth() {
+        assert_args_eq!(
+            ["curl", "-u", "user:pass", "https://test.com"],
+            vec!["https://test.com", "Authorization:Basic dXNlcjpwYXNz"]
+        );
+    }
+
+    #[test]
+    fn data() {
+        assert_args_eq!(
+            ["curl", "https://test.com", "-d", "a=1", "--data-raw", "b=2"],
+            vec!["https://test.com", "Content-Type:application/x-www-form-urlencoded", "--raw=a=1&b=2"]
+        );
+        assert_args_eq!(
+            ["curl", "https://test.com", "-H", "content-type: application/json", "--data-raw", r#"{"a":1}"#],
+            vec!["https://test.com", "content-type:application/json", r#"--raw={"a":1}"#]
+        );
+        assert_args_eq!(
+            ["curl", "-X", "PUT", "https://test.com", "--json", r#"{"a":1}"#],
+            vec!["PUT", "https://test.com", "Content-Type:application/json", "Accept:application/json", r#"--raw={"a":1}"#]
+        );
+        assert_args_eq!(
+            ["curl", "https://test.com", "--data-urlencode", "q=a b&c"],
+            vec!["https://test.com", "Content-Type:application/x-www-form-urlencoded", "--raw=q=a+b%26c"]
+        );
+    }
+
+    #[test]
+    fn data_as_query() {
+        assert_args_eq!(["curl", "-G", "https://test.com?x=0", "-d", "a=1", "-d", "b=2"], vec!["https://test.com?x=0&a=1&b=2"]);
+    }
+
+    #[test]
+    fn ignored_options() {
+        assert_args_eq!(
+            ["curl", "-sSL", "--compressed", "-o", "out.txt", "--max-time", "5", "https://test.com"],
+            vec!["https://test.com"]
+        );
+    }
+
+    #[test]
+    fn tls_options() {
+        assert_args_eq!(["curl", "--cacert", "ca.pem", "https://test.com"], vec!["https://test.com", "--cafile=ca.pem"]);
+        assert_args_eq!(["curl", "-skL", "https://test.com"], vec!["https://test.com", "--insecure"]);
+    }
+
+    #[test]
+    fn errors() {
+        assert_error_eq!(["curl"], Error::NoCommand);
+        assert_error_eq!(["curl", "-s"], Error::MissingUrl);
+        assert_error_eq!(["curl", "https://test.com", "-H"], Error::MissingValue("-H".into()));
+        assert_error_eq!(["curl", "https://test.com", "-F", "a=b"], Error::UnsupportedOption("--form".into()));
+        assert_error_eq!(["curl", "https://test.com", "--unknown"], Error::UnsupportedOption("--unknown".into()));
+        assert_error_eq!(["curl", "https://test.com", "-sz"], Error::UnsupportedOption("-z".into()));
+    }
+}
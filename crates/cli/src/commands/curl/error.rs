@@ -0,0 +1,39 @@
+use crate::core::Error as CoreError;
+use std::fmt;
+use std::io;
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(PartialEq)]
+pub enum Error {
+    NoCommand,
+    UnterminatedQuote,
+    MissingUrl,
+    MissingValue(String),
+    UnsupportedOption(String),
+    Io(String),
+}
+
+impl From<Error> for CoreError {
+    fn from(err: Error) -> CoreError {
+        CoreError::FromCurl(err)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Error {
+        Error::Io(err.to_string())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::NoCommand => write!(f, "missing curl command"),
+            Error::UnterminatedQuote => write!(f, "unterminated quote in the curl command"),
+            Error::MissingUrl => write!(f, "no URL found in the curl command"),
+            Error::MissingValue(option) => write!(f, "missing value for the curl option '{}'", option),
+            Error::UnsupportedOption(option) => write!(f, "the curl option '{}' is not supported", option),
+            Error::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+// FIXME Duplicated code with HTTP command
+
+const LONG_FLAG_WIDTH: usize = 15;
+use crate::commands::curl::COMMAND_FROM_CURL;
+use crate::rh_name;
+
+macro_rules! newline {
+    () => {
+        println!("")
+    };
+}
+macro_rules! flags {
+    ($description:expr, $long:expr) => {
+        println!("      --{:long$} {}", $long, $description, long = LONG_FLAG_WIDTH)
+    };
+}
+#[cfg(feature = "alias")]
+macro_rules! key_value {
+    ($description:expr, $long:expr) => {
+        println!("      {:long$} {}", $long, $description, long = LONG_FLAG_WIDTH + 2)
+    };
+}
+macro_rules! text {
+    ($description:expr) => {
+        println!("  {:long$} {}", "", $description, long = 3)
+    };
+}
+
+macro_rules! options {
+    () => {
+        println!("OPTIONS:");
+        flags!("Print the equivalent rh arguments instead of sending the request", "print");
+        flags!("Show this screen", "help");
+    };
+}
+#[cfg(feature = "alias")]
+macro_rules! alias {
+    () => {
+        println!("ALIAS:");
+        key_value!("Save the converted arguments as an alias instead of sending the request", "@alias");
+    };
+}
+macro_rules! command {
+    () => {
+        println!("CURL COMMAND:");
+        text!("The whole curl command line as a single argument, or read from stdin if there is no argument");
+        text!("Options such as -X, -H, -d, --data-raw, --json, -u, -G, -I or --cacert are converted");
+    };
+}
+
+macro_rules! thanks {
+    () => {
+        println!("Thanks for using {}!", rh_name!())
+    };
+}
+
+pub fn show() {
+    println!("USAGE:");
+    #[cfg(feature = "alias")]
+    {
+        text!(format!("{} {} [options] [@alias] \"<curl command>\"", rh_name!(), COMMAND_FROM_CURL));
+        text!(format!("{} {} [options] [@alias] < command.txt", rh_name!(), COMMAND_FROM_CURL));
+    }
+    #[cfg(not(feature = "alias"))]
+    {
+        text!(format!("{} {} [options] \"<curl command>\"", rh_name!(), COMMAND_FROM_CURL));
+        text!(format!("{} {} [options] < command.txt", rh_name!(), COMMAND_FROM_CURL));
+    }
+
+    newline!();
+    options!();
+    #[cfg(feature = "alias")]
+    {
+        newline!();
+        alias!();
+    }
+    newline!();
+    command!();
+    newline!();
+    thanks!();
+}
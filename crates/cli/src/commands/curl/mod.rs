@@ -0,0 +1,134 @@
+mod convert;
+mod error;
+mod help;
+mod words;
+
+#[cfg(feature = "alias")]
+use super::ALIAS_NAME_PREFIX;
+use super::{Command, DonePtr};
+use crate::commands::http;
+use crate::core::Args;
+use crate::parser;
+use crate::rh_name;
+use crate::shell::message::MessageRender;
+use crate::shell::os::OsDirs;
+use crate::shell::quote;
+use crate::shell::stream;
+use crate::shell::Shell;
+pub(crate) use error::Error;
+use std::io::{self, Read, Write};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+pub const COMMAND_FROM_CURL: &str = "from-curl";
+const PRINT_FLAG: &str = "--print";
+
+pub struct FromCurlCommand;
+
+impl<OD: OsDirs, O: Write, E: Write> Command<OD, O, E> for FromCurlCommand {
+    fn execute(&self, shell: &mut Shell<OD, O, E>, args: &mut Args, _: DonePtr) -> super::Result<()> {
+        remove_the_first_arg_that_is_the_from_curl_command(args);
+
+        let mut print = false;
+        #[cfg(feature = "alias")]
+        let mut alias_name: Option<String> = None;
+        while let Some(first) = args.first() {
+            if first == "--help" || first == "-h" {
+                help::show();
+                return Ok(());
+            } else if first == PRINT_FLAG {
+                print = true;
+            } else {
+                #[cfg(feature = "alias")]
+                if let Some(name) = first.strip_prefix(ALIAS_NAME_PREFIX).filter(|name| !name.is_empty()) {
+                    alias_name = Some(name.to_lowercase());
+                    args.remove(0);
+                    continue;
+                }
+                break;
+            }
+            args.remove(0);
+        }
+
+        let rh_args = convert::to_args(&curl_words(args)?)?;
+
+        if print {
+            shell.out(MessageRender::new(format!("{} {}", rh_name!(), quote::join(&rh_args))))?;
+        }
+
+        // The command is printed before being saved, but it's never executed with an alias
+        #[cfg(feature = "alias")]
+        if let Some(name) = alias_name {
+            crate::commands::alias::storage::store::save(shell.os_dirs(), &name, &rh_args)?;
+            shell.out(MessageRender::new(format!("Alias '{}' saved", name)))?;
+            return Ok(());
+        }
+
+        if print {
+            Ok(())
+        } else {
+            let ws = parser::execute_without_stdin(&rh_args)?;
+            http::execute_workspace(shell, ws)
+        }
+    }
+}
+
+fn remove_the_first_arg_that_is_the_from_curl_command(args: &mut Args) {
+    if !args.is_empty() {
+        args.remove(0);
+    }
+}
+
+// A single argument is a whole command line to split, several arguments are already split by the shell
+fn curl_words(args: &Args) -> Result<Vec<String>> {
+    match args.len() {
+        0 => {
+            if stream::is_stdin() {
+                return Err(Error::NoCommand);
+            }
+            let mut command = String::new();
+            io::stdin().read_to_string(&mut command)?;
+            words::split(&command)
+        }
+        1 => words::split(&args[0]),
+        _ => Ok(args.clone()),
+    }
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alias")]
+    mod alias {
+        use super::super::FromCurlCommand;
+        use crate::commands::alias::storage::load;
+        use crate::commands::{Command, ALIAS_NAME_PREFIX};
+        use crate::shell::Shell;
+        use crate::test::alias::*;
+        use crate::test::os::TestValidOsDirs;
+
+        #[test]
+        fn print_and_save() {
+            setup();
+            let mut args = rh_test::args![
+                "from-curl",
+                "--print",
+                rh_test::arg_alias!(CUSTOM_ALIAS_NAME_1),
+                "curl -X PUT https://example.com -H 'X-Key: 1'"
+            ];
+            let os_dirs = TestValidOsDirs::new();
+            let mut out = Vec::new();
+            let mut shell = Shell::new(&os_dirs, &mut out, Vec::new());
+            FromCurlCommand {}.execute(&mut shell, &mut args, || {}).unwrap();
+            drop(shell);
+
+            let rh_args = load::from_name(&os_dirs, CUSTOM_ALIAS_NAME_1).unwrap();
+            assert_eq!(rh_args, vec!["PUT", "https://example.com", "--raw-header=X-Key:1"]);
+            assert_eq!(
+                String::from_utf8(out).unwrap(),
+                format!("rh PUT https://example.com --raw-header=X-Key:1\nAlias '{}' saved\n", CUSTOM_ALIAS_NAME_1)
+            );
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use super::{Error, Result};
+use std::iter::Peekable;
+use std::str::Chars;
+
+// Splits a command line the way a POSIX shell would (quotes, escapes, line continuations),
+// including the $'...' strings produced by the "Copy as cURL" of the browsers
+pub fn split(command: &str) -> Result<Vec<String>> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut chars = command.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            ' ' | '\t' | '\r' | '\n' => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            '\\' => match chars.next() {
+                Some('\n') => {}
+                Some('\r') if chars.peek() == Some(&'\n') => {
+                    chars.next();
+                }
+                Some(escaped) => {
+                    word.push(escaped);
+                    in_word = true;
+                }
+                None => {}
+            },
+            '\'' => {
+                single_quoted(&mut chars, &mut word)?;
+                in_word = true;
+            }
+            '"' => {
+                double_quoted(&mut chars, &mut word)?;
+                in_word = true;
+            }
+            '$' if chars.peek() == Some(&'\'') => {
+                chars.next();
+                ansi_c_quoted(&mut chars, &mut word)?;
+                in_word = true;
+            }
+            _ => {
+                word.push(c);
+                in_word = true;
+            }
+        }
+    }
+    if in_word {
+        words.push(word);
+    }
+    Ok(words)
+}
+
+fn single_quoted(chars: &mut Peekable<Chars>, word: &mut String) -> Result<()> {
+    for c in chars.by_ref() {
+        if c == '\'' {
+            return Ok(());
+        }
+        word.push(c);
+    }
+    Err(Error::UnterminatedQuote)
+}
+
+fn double_quoted(chars: &mut Peekable<Chars>, word: &mut String) -> Result<()> {
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return Ok(()),
+            '\\' => match chars.next() {
+                Some('\n') => {}
+                Some(escaped @ ('"' | '\\' | '$' | '`')) => word.push(escaped),
+                Some(other) => {
+                    word.push('\\');
+                    word.push(other);
+                }
+                None => break,
+            },
+            _ => word.push(c),
+        }
+    }
+    Err(Error::UnterminatedQuote)
+}
+
+fn ansi_c_quoted(chars: &mut Peekable<Chars>, word: &mut String) -> Result<()> {
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => return Ok(()),
+            '\\' => match chars.next() {
+                Some('n') => word.push('\n'),
+                Some('t') => word.push('\t'),
+                Some('r') => word.push('\r'),
+                Some('0') => word.push('\0'),
+                Some('x') => push_code_point(chars, word, 2),
+                Some('u') => push_code_point(chars, word, 4),
+                Some('U') => push_code_point(chars, word, 8),
+                Some(escaped) => word.push(escaped),
+                None => break,
+            },
+            _ => word.push(c),
+        }
+    }
+    Err(Error::UnterminatedQuote)
+}
+
+fn push_code_point(chars: &mut Peekable<Chars>, word: &mut String, max_digits: usize) {
+    let mut digits = String::new();
+    while digits.len() < max_digits {
+        match chars.peek() {
+            Some(c) if c.is_ascii_hexdigit() => digits.push(chars.next().unwrap()),
+            _ => break,
+        }
+    }
+    match u32::from_str_radix(&digits, 16).ok().and_then(char::from_u32) {
+        Some(c) => word.push(c),
+        None => word.push_str(&digits),
+    }
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{split, Error};
+
+    #[test]
+    fn plain_words() {
+        assert_eq!(split("curl  https://test.com\t-s").unwrap(), vec!["curl", "https://test.com", "-s"]);
+        assert_eq!(split("").unwrap(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn quotes() {
+        assert_eq!(split(r#"curl 'a b' "c d" e'f'"g""#).unwrap(), vec!["curl", "a b", "c d", "efg"]);
+        assert_eq!(split(r#"-H "X-Key: \"quoted\" \$HOME""#).unwrap(), vec!["-H", r#"X-Key: "quoted" $HOME"#]);
+        assert_eq!(split(r#"'it'\''s'"#).unwrap(), vec!["it's"]);
+        assert_eq!(split("''").unwrap(), vec![""]);
+    }
+
+    #[test]
+    fn ansi_c_quotes() {
+        assert_eq!(split(r#"$'{"a":\'b\'}\n'"#).unwrap(), vec!["{\"a\":'b'}\n"]);
+        assert_eq!(split(r#"$'café \x41'"#).unwrap(), vec!["café A"]);
+    }
+
+    #[test]
+    fn line_continuations() {
+        assert_eq!(
+            split("curl https://test.com \\\n  -H 'A: b' \\\r\n  -s").unwrap(),
+            vec!["curl", "https://test.com", "-H", "A: b", "-s"]
+        );
+    }
+
+    #[test]
+    fn error_if_unterminated_quote() {
+        assert_eq!(split("curl 'https://test.com").unwrap_err(), Error::UnterminatedQuote);
+        assert_eq!(split("curl \"https://test.com").unwrap_err(), Error::UnterminatedQuote);
+        assert_eq!(split("curl $'https://test.com").unwrap_err(), Error::UnterminatedQuote);
+    }
+}
@@ -27,7 +27,7 @@ fn show_program() {
 fn show_directories() {
     use crate::shell::os::DefaultOsDirs;
 
-    let os_dirs = DefaultOsDirs::default();
+    let os_dirs = DefaultOsDirs;
     let mut config_dir_exists = false;
     match os_dirs.config_directory() {
         Some(path) => {
@@ -54,5 +54,5 @@ fn show_directories() {
 }
 
 fn show_env_vars() {
-    env::vars().into_iter().for_each(|(name, value)| println!("{:width$} {}", name, value, width = KEY_WIDTH));
+    env::vars().for_each(|(name, value)| println!("{:width$} {}", name, value, width = KEY_WIDTH));
 }
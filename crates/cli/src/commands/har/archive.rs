@@ -0,0 +1,330 @@
+use super::{Error, Result};
+use crate::core::Workspace;
+use crate::request::body::build_body;
+use crate::request::charset;
+use crate::request::cookie;
+use crate::request::date;
+use crate::request::timing::Timing;
+use crate::request::{HeaderMap, Response};
+use crate::{rh_name, rh_version};
+use content_inspector::inspect;
+use reqwest::header::{CONTENT_TYPE, COOKIE, LOCATION, SET_COOKIE};
+use serde_json::{json, Map, Value};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::time::Duration;
+use url::Url;
+
+const HAR_VERSION: &str = "1.2";
+// Set by the client or only valid for a connection, the other headers are replayed as recorded
+const SKIPPED_HEADERS: [&str; 7] = ["host", "content-length", "connection", "keep-alive", "transfer-encoding", "upgrade", "accept-encoding"];
+
+// A request of a HAR entry, to send again
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub struct HarRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+// The entries of a HAR file, for example exported from the browser dev tools
+pub fn read(path: &str) -> Result<Vec<Value>> {
+    let content = fs::read_to_string(path)?;
+    let mut har = parse(path, &content)?;
+    Ok(entries(&mut har).map(std::mem::take).unwrap_or_default())
+}
+
+// The file is created if it doesn't exist
+pub fn append(path: &str, entry: Value) -> Result<()> {
+    let mut har = match fs::read_to_string(path) {
+        Ok(content) if !content.trim().is_empty() => parse(path, &content)?,
+        Ok(_) => new_har(),
+        Err(err) if err.kind() == ErrorKind::NotFound => new_har(),
+        Err(err) => return Err(err.into()),
+    };
+    match entries(&mut har) {
+        Some(entries) => entries.push(entry),
+        None => return Err(Error::InvalidFile(path.to_string(), "log.entries isn't an array".into())),
+    }
+    if let Some(parent) = Path::new(path).parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    let content = serde_json::to_string_pretty(&har).map_err(|err| Error::Io(err.to_string()))?;
+    fs::write(path, content)?;
+    Ok(())
+}
+
+fn parse(path: &str, content: &str) -> Result<Value> {
+    let mut har = serde_json::from_str::<Value>(content).map_err(|err| Error::InvalidFile(path.to_string(), err.to_string()))?;
+    if entries(&mut har).is_none() {
+        return Err(Error::InvalidFile(path.to_string(), "log.entries isn't an array".into()));
+    }
+    Ok(har)
+}
+
+fn entries(har: &mut Value) -> Option<&mut Vec<Value>> {
+    har.get_mut("log")?.get_mut("entries")?.as_array_mut()
+}
+
+fn new_har() -> Value {
+    json!({
+        "log": {
+            "version": HAR_VERSION,
+            "creator": {"name": rh_name!(), "version": rh_version!()},
+            "entries": [],
+        }
+    })
+}
+
+// An exchange in the HAR 1.2 format (http://www.softwareishard.com/blog/har-12-spec/)
+pub fn entry(ws: &Workspace, req_number: u8, response: &Response, body: &[u8], timing: Option<&Timing>) -> Value {
+    let now = date::now_millis();
+    let total = timing.map(|timing| millis(timing.total)).unwrap_or(0.0);
+    let version = format!("{:?}", response.version());
+    let url = &ws.urls[req_number as usize];
+    let headers = ws.headers.borrow();
+
+    let mut request = json!({
+        "method": ws.method.as_str(),
+        "url": url,
+        "httpVersion": version,
+        "cookies": request_cookies(&headers),
+        "headers": name_values(&headers),
+        "queryString": query_string(url),
+        "headersSize": -1,
+        "bodySize": 0,
+    });
+    if let Some(text) = build_body(ws).filter(|text| !text.is_empty()) {
+        request["bodySize"] = json!(text.len());
+        request["postData"] = json!({"mimeType": header(&headers, CONTENT_TYPE.as_str()), "text": text});
+    }
+
+    let response_headers = response.headers();
+    let mut content = json!({
+        "size": body.len(),
+        "mimeType": header(response_headers, CONTENT_TYPE.as_str()),
+    });
+    let decoded = charset::decode(body, ws.charset.or_else(|| charset::from_headers(response_headers)));
+    match decoded {
+        None if inspect(body).is_binary() => {
+            content["text"] = json!(base64::encode(body));
+            content["encoding"] = json!("base64");
+        }
+        decoded => content["text"] = json!(decoded.unwrap_or_else(|| String::from_utf8_lossy(body))),
+    }
+
+    json!({
+        "startedDateTime": date::iso_date(now - total as i64),
+        "time": total,
+        "request": request,
+        "response": {
+            "status": response.status().as_u16(),
+            "statusText": response.status().canonical_reason().unwrap_or(""),
+            "httpVersion": version,
+            "cookies": response_cookies(response_headers),
+            "headers": name_values(response_headers),
+            "content": content,
+            "redirectURL": header(response_headers, LOCATION.as_str()),
+            "headersSize": -1,
+            "bodySize": body.len(),
+        },
+        "cache": {},
+        "timings": timings(timing),
+    })
+}
+
+fn name_values(headers: &HeaderMap) -> Value {
+    let values: Vec<Value> = headers
+        .iter()
+        .map(|(name, value)| json!({"name": name.as_str(), "value": String::from_utf8_lossy(value.as_bytes())}))
+        .collect();
+    Value::Array(values)
+}
+
+fn header(headers: &HeaderMap, name: &str) -> String {
+    headers.get(name).and_then(|value| value.to_str().ok()).unwrap_or("").to_string()
+}
+
+fn query_string(url: &str) -> Value {
+    let pairs: Vec<Value> = match Url::parse(url) {
+        Ok(url) => url.query_pairs().map(|(name, value)| json!({"name": name, "value": value})).collect(),
+        Err(_) => Vec::new(),
+    };
+    Value::Array(pairs)
+}
+
+fn request_cookies(headers: &HeaderMap) -> Value {
+    let cookies: Vec<Value> = headers
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|cookie| cookie.trim().split_once('='))
+        .map(|(name, value)| json!({"name": name, "value": value}))
+        .collect();
+    Value::Array(cookies)
+}
+
+fn response_cookies(headers: &HeaderMap) -> Value {
+    let now = date::now();
+    let cookies: Vec<Value> = headers
+        .get_all(SET_COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .filter_map(|value| cookie::parse(value, now))
+        .map(|cookie| {
+            let mut map = Map::new();
+            map.insert("name".into(), json!(cookie.name));
+            map.insert("value".into(), json!(cookie.value));
+            if let Some(path) = cookie.path {
+                map.insert("path".into(), json!(path));
+            }
+            if let Some(domain) = cookie.domain {
+                map.insert("domain".into(), json!(domain));
+            }
+            if let Some(expires) = cookie.expires {
+                map.insert("expires".into(), json!(date::iso_date(expires.saturating_mul(1000))));
+            }
+            map.insert("httpOnly".into(), json!(cookie.http_only));
+            map.insert("secure".into(), json!(cookie.secure));
+            Value::Object(map)
+        })
+        .collect();
+    Value::Array(cookies)
+}
+
+// -1 if a phase doesn't apply (e.g. no TLS handshake), the connect time includes the TLS handshake
+fn timings(timing: Option<&Timing>) -> Value {
+    let phase = |duration: Option<Duration>| duration.map_or(json!(-1), |duration| json!(millis(duration)));
+    match timing {
+        Some(timing) => json!({
+            "blocked": -1,
+            "dns": phase(timing.dns),
+            "connect": phase(timing.connect.map(|connect| connect + timing.tls.unwrap_or_default())),
+            "ssl": phase(timing.tls),
+            "send": 0,
+            "wait": millis(timing.first_byte),
+            "receive": millis(timing.download),
+        }),
+        None => json!({"send": 0, "wait": 0, "receive": 0}),
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    (duration.as_secs_f64() * 1_000_000.0).round() / 1000.0
+}
+
+// The method, URL, headers and body of an entry, the number is only used in the errors
+pub fn request(entry: &Value, number: usize) -> Result<HarRequest> {
+    let invalid = |reason: &str| Error::InvalidEntry(number, reason.to_string());
+    let request = entry.get("request").ok_or_else(|| invalid("there is no request"))?;
+    let method = request.get("method").and_then(Value::as_str).ok_or_else(|| invalid("there is no method"))?;
+    let url = request.get("url").and_then(Value::as_str).ok_or_else(|| invalid("there is no URL"))?;
+    let headers = request
+        .get("headers")
+        .and_then(Value::as_array)
+        .map(|headers| {
+            headers
+                .iter()
+                .filter_map(|header| Some((header.get("name")?.as_str()?, header.get("value")?.as_str()?)))
+                // The HTTP/2 pseudo-headers (e.g. :authority) are part of the URL and method
+                .filter(|(name, _)| !name.starts_with(':') && !SKIPPED_HEADERS.contains(&name.to_ascii_lowercase().as_str()))
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+    let body = request
+        .pointer("/postData/text")
+        .and_then(Value::as_str)
+        .filter(|text| !text.is_empty())
+        .map(str::to_string);
+    Ok(HarRequest {
+        method: method.to_string(),
+        url: url.to_string(),
+        headers,
+        body,
+    })
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{append, query_string, read, request, timings, Error, HarRequest};
+    use crate::request::timing::Timing;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn replayed_request() {
+        let entry = json!({
+            "request": {
+                "method": "POST",
+                "url": "https://example.com/api?id=1",
+                "headers": [
+                    {"name": ":authority", "value": "example.com"},
+                    {"name": "Host", "value": "example.com"},
+                    {"name": "Content-Type", "value": "application/json"},
+                    {"name": "Accept-Encoding", "value": "gzip, br"},
+                    {"name": "X-Request-Id", "value": "42"},
+                ],
+                "postData": {"mimeType": "application/json", "text": "{\"id\":1}"},
+            }
+        });
+        assert_eq!(
+            request(&entry, 1),
+            Ok(HarRequest {
+                method: "POST".into(),
+                url: "https://example.com/api?id=1".into(),
+                headers: vec![("Content-Type".into(), "application/json".into()), ("X-Request-Id".into(), "42".into())],
+                body: Some("{\"id\":1}".into()),
+            })
+        );
+        assert_eq!(request(&json!({"request": {"method": "GET"}}), 3), Err(Error::InvalidEntry(3, "there is no URL".into())));
+    }
+
+    #[test]
+    fn har_file() {
+        let path = std::env::temp_dir().join(format!("rh-har-{}.har", std::process::id()));
+        let path = path.to_str().unwrap();
+        let _ = std::fs::remove_file(path);
+
+        append(path, json!({"request": {"method": "GET", "url": "http://localhost/1"}})).unwrap();
+        append(path, json!({"request": {"method": "GET", "url": "http://localhost/2"}})).unwrap();
+        let entries = read(path).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1]["request"]["url"], "http://localhost/2");
+
+        std::fs::write(path, "{\"log\": {}}").unwrap();
+        assert!(matches!(append(path, json!({})), Err(Error::InvalidFile(_, _))));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn query() {
+        assert_eq!(
+            query_string("http://localhost/?a=1&b=x%20y"),
+            json!([{"name": "a", "value": "1"}, {"name": "b", "value": "x y"}])
+        );
+        assert_eq!(query_string("http://localhost/"), json!([]));
+    }
+
+    #[test]
+    fn http_timings() {
+        let timing = Timing {
+            dns: Some(Duration::from_millis(2)),
+            connect: Some(Duration::from_millis(10)),
+            tls: None,
+            first_byte: Duration::from_millis(30),
+            download: Duration::from_millis(5),
+            total: Duration::from_millis(47),
+            size: 100,
+        };
+        assert_eq!(
+            timings(Some(&timing)),
+            json!({"blocked": -1, "dns": 2.0, "connect": 10.0, "ssl": -1, "send": 0, "wait": 30.0, "receive": 5.0})
+        );
+    }
+}
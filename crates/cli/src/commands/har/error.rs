@@ -0,0 +1,45 @@
+use crate::core::Error as CoreError;
+use std::fmt;
+use std::io;
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(PartialEq)]
+pub enum Error {
+    MissingAction,
+    UnknownAction(String),
+    MissingFile,
+    InvalidFile(String, String),
+    NoEntries(String),
+    EntryNotFound(usize, usize),
+    InvalidEntry(usize, String),
+    Unexpected(String),
+    Io(String),
+}
+
+impl From<Error> for CoreError {
+    fn from(err: Error) -> CoreError {
+        CoreError::Har(err)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Error {
+        Error::Io(err.to_string())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::MissingAction => write!(f, "missing action"),
+            Error::UnknownAction(action) => write!(f, "unknown action '{}'", action),
+            Error::MissingFile => write!(f, "missing HAR file"),
+            Error::InvalidFile(path, err) => write!(f, "'{}' is not a valid HAR file, {}", path, err),
+            Error::NoEntries(path) => write!(f, "there is no entry in '{}'", path),
+            Error::EntryNotFound(number, count) => write!(f, "there is no entry {}, the file has {} entries", number, count),
+            Error::InvalidEntry(number, err) => write!(f, "the entry {} can't be replayed, {}", number, err),
+            Error::Unexpected(arg) => write!(f, "found argument '{}' which wasn't expected, the request comes from the HAR file", arg),
+            Error::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
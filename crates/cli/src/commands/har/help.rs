@@ -0,0 +1,57 @@
+// FIXME Duplicated code with HTTP command
+
+const LONG_FLAG_WIDTH: usize = 15;
+use crate::commands::har::{COMMAND_HAR, REPLAY_ACTION};
+use crate::rh_name;
+
+macro_rules! newline {
+    () => {
+        println!("")
+    };
+}
+macro_rules! flags {
+    ($description:expr, $long:expr) => {
+        println!("      --{:long$} {}", $long, $description, long = LONG_FLAG_WIDTH)
+    };
+}
+macro_rules! text {
+    ($description:expr) => {
+        println!("  {:long$} {}", "", $description, long = 3)
+    };
+}
+
+macro_rules! options {
+    () => {
+        println!("OPTIONS:");
+        flags!("Replay the entry N only (the first entry is 1), all the entries by default", "entry=N");
+        flags!("Show this screen", "help");
+        text!(format!(
+            "The options of {} (e.g. -Uh, --timing or --har=<file>) are used to show the responses",
+            rh_name!()
+        ));
+    };
+}
+macro_rules! record {
+    () => {
+        println!("RECORD:");
+        text!(format!("{} <url> --har=<file> appends the request and the response to a HAR file", rh_name!()));
+        text!("The HAR files of the browser dev tools can be replayed too");
+    };
+}
+
+macro_rules! thanks {
+    () => {
+        println!("Thanks for using {}!", rh_name!())
+    };
+}
+
+pub fn show() {
+    println!("USAGE:");
+    text!(format!("{} {} {} <file.har> [--entry=N] [options]", rh_name!(), COMMAND_HAR, REPLAY_ACTION));
+    newline!();
+    options!();
+    newline!();
+    record!();
+    newline!();
+    thanks!();
+}
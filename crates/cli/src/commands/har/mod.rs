@@ -0,0 +1,129 @@
+pub(crate) mod archive;
+mod error;
+mod help;
+
+use super::{Command, DonePtr};
+use crate::commands::http;
+use crate::core::Args;
+use crate::parser;
+use crate::request::{HeaderMap, Method};
+use crate::shell::os::OsDirs;
+use crate::shell::Shell;
+use archive::HarRequest;
+pub(crate) use error::Error;
+use reqwest::header::{HeaderName, HeaderValue};
+use std::io::Write;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+pub const COMMAND_HAR: &str = "har";
+pub const REPLAY_ACTION: &str = "replay";
+const ENTRY_FLAG: &str = "--entry=";
+
+pub struct HarCommand;
+
+impl<OD: OsDirs, O: Write, E: Write> Command<OD, O, E> for HarCommand {
+    fn execute(&self, shell: &mut Shell<OD, O, E>, args: &mut Args, _: DonePtr) -> super::Result<()> {
+        remove_the_first_arg_that_is_the_har_command(args);
+
+        match args.first().map(String::as_str) {
+            Some("--help") | Some("-h") => {
+                help::show();
+                return Ok(());
+            }
+            Some(REPLAY_ACTION) => {
+                args.remove(0);
+            }
+            Some(action) => return Err(Error::UnknownAction(action.to_string()).into()),
+            None => return Err(Error::MissingAction.into()),
+        }
+
+        let path = match args.first() {
+            Some(path) if !path.starts_with('-') => args.remove(0),
+            _ => return Err(Error::MissingFile.into()),
+        };
+        let (entry_number, flags) = entry_number_and_flags(args)?;
+
+        let entries = archive::read(&path)?;
+        if entries.is_empty() {
+            return Err(Error::NoEntries(path).into());
+        }
+        let numbers = match entry_number {
+            Some(number) if number == 0 || number > entries.len() => return Err(Error::EntryNotFound(number, entries.len()).into()),
+            Some(number) => number..=number,
+            None => 1..=entries.len(),
+        };
+        for number in numbers {
+            let request = archive::request(&entries[number - 1], number)?;
+            replay(shell, &request, &flags, number)?;
+        }
+        Ok(())
+    }
+}
+
+fn remove_the_first_arg_that_is_the_har_command(args: &mut Args) {
+    if !args.is_empty() {
+        args.remove(0);
+    }
+}
+
+// The flags only change how the responses are shown, the requests come from the HAR file
+fn entry_number_and_flags(args: &Args) -> Result<(Option<usize>, Vec<String>)> {
+    let mut entry_number = None;
+    let mut flags = Vec::new();
+    for arg in args.iter() {
+        if let Some(number) = arg.strip_prefix(ENTRY_FLAG) {
+            entry_number = Some(number.parse().map_err(|_| Error::Unexpected(arg.clone()))?);
+        } else if arg.starts_with('-') {
+            flags.push(arg.clone());
+        } else {
+            return Err(Error::Unexpected(arg.clone()));
+        }
+    }
+    Ok((entry_number, flags))
+}
+
+// The workspace is built from the flags, then the request is the one of the entry
+fn replay<OD: OsDirs, O: Write, E: Write>(shell: &mut Shell<OD, O, E>, request: &HarRequest, flags: &[String], number: usize) -> super::Result<()> {
+    let mut args = vec![request.url.clone()];
+    args.extend(flags.iter().cloned());
+    let mut ws = parser::execute_without_stdin(&args)?;
+    ws.urls = vec![request.url.clone()];
+    ws.method = Method::from_bytes(request.method.as_bytes()).map_err(|_| Error::InvalidEntry(number, format!("invalid method '{}'", request.method)))?;
+    ws.raw = request.body.clone();
+    *ws.headers.borrow_mut() = headers(request, number)?;
+    http::execute_workspace(shell, ws)
+}
+
+fn headers(request: &HarRequest, number: usize) -> Result<HeaderMap> {
+    let mut headers = HeaderMap::new();
+    for (name, value) in request.headers.iter() {
+        let invalid = || Error::InvalidEntry(number, format!("invalid header '{}'", name));
+        let name = HeaderName::from_bytes(name.as_bytes()).map_err(|_| invalid())?;
+        let value = HeaderValue::from_str(value).map_err(|_| invalid())?;
+        headers.append(name, value);
+    }
+    Ok(headers)
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{entry_number_and_flags, Error};
+
+    #[test]
+    fn entry_and_flags() {
+        let args = rh_test::args!["--entry=2", "-Uh", "--timing"];
+        assert_eq!(entry_number_and_flags(&args), Ok((Some(2), rh_test::args!["-Uh", "--timing"])));
+
+        let args = rh_test::args!["-s"];
+        assert_eq!(entry_number_and_flags(&args), Ok((None, rh_test::args!["-s"])));
+
+        let args = rh_test::args!["--entry=first"];
+        assert_eq!(entry_number_and_flags(&args), Err(Error::Unexpected("--entry=first".into())));
+
+        let args = rh_test::args!["X-Key:Value"];
+        assert_eq!(entry_number_and_flags(&args), Err(Error::Unexpected("X-Key:Value".into())));
+    }
+}
@@ -3,6 +3,8 @@
 const LONG_FLAG_WIDTH: usize = 15;
 #[cfg(feature = "alias")]
 use crate::commands::alias::COMMAND_ALIAS;
+use crate::commands::curl::COMMAND_FROM_CURL;
+use crate::commands::har::COMMAND_HAR;
 use crate::rh_name;
 
 macro_rules! newline {
@@ -78,9 +80,31 @@ macro_rules! options {
         println!("OPTIONS:");
         flags!("Show version", "version");
         flags!("Show this screen", "help");
+        flags!("Print the equivalent curl command instead of sending the request", "as-curl");
         flags!("Show a symbol for the request part and another one for the response part", "direction", "d");
-        flags!("Colorize the output (shortcut: --pretty=c)", "pretty=color");
-        flags!("Show more details, shortcut for -UHBshb", "verbose", "v");
+        flags!(
+            "Colorize and format the output (default if not redirected, shortcut: --pretty=c or --pretty=all)",
+            "pretty=color"
+        );
+        flags!("Colorize the output only (alias: --pretty=colors-only)", "pretty=colors");
+        flags!("Format the output only", "pretty=format");
+        flags!("Neither colorize nor format (default if redirected)", "pretty=none");
+        flags!("Don't colorize the output, like $NO_COLOR (see also $CLICOLOR_FORCE)", "no-color");
+        flags!("Sort the headers and the JSON keys", "sort");
+        flags!("Sort the headers only", "sort=h");
+        flags!("Sort the JSON keys only", "sort=j");
+        flags!("Select values of a JSON response (e.g. .items[].id or ..error)", "filter=<expr>");
+        flags!("Convert a JSON response to YAML, TOML or JSON (default)", "output-format=yaml|toml|json");
+        flags!("Show a JSON array of objects as a table, optionally some columns only", "table[=<columns>]");
+        flags!("Show the response body as a hex dump, optionally the first N bytes only", "hexdump[=N]");
+        flags!("Print the request, the response and the times as one JSON document", "output=json");
+        flags!("Append the request and the response to an HTTP Archive (HAR) file", "har=<file>");
+        flags!("Decode the response body with this charset instead of the Content-Type one", "charset=<name>");
+        flags!("Show the first N bytes of the response body only", "max-body=N");
+        flags!("Use $PAGER (default: less -R) if the output doesn't fit in the terminal", "pager=auto");
+        flags!("Use $PAGER even if the output fits in the terminal, or never", "pager=always|never");
+        flags!("Use a theme of the config directory, see the themes documentation", "theme=<name>");
+        flags!("Show more details, shortcut for -UHBshb --tls-info", "verbose", "v");
         flags!("Show the request and response headers", "headers");
         flags!("Show the request URL and method", "url", "U");
         flags!("Show the request header", "req-header", "H");
@@ -91,17 +115,29 @@ macro_rules! options {
         flags!("Show the response body (default)", "body", "b");
         flags!("Hide the response body", "body=n");
         flags!("Compact the response body", "compact", "c");
+        flags!("Show the DNS, TCP, TLS, first byte and download times", "timing");
+        flags!("Show the times as JSON (on stderr if the output is redirected)", "timing=json");
+        flags!("Show the TLS version, cipher suite, ALPN and certificate chain", "tls-info");
+        flags!("Explain the status code and the headers such as Retry-After", "explain");
+        flags!("Decode the JWTs of Authorization, Set-Cookie and the JSON strings (not verified)", "decode-jwt");
+        flags!("Show the cookies of Set-Cookie as a table, with warnings", "cookies");
+        flags!("Don't verify the TLS certificates (not secure)", "insecure", "k");
+        flags!("Don't verify the hostname of the TLS certificates (not secure)", "insecure-hostname");
         newline!();
         key_value!("Combine any short flags, for example:", "-cUh...");
         right_text!("-c compact the response");
         right_text!("-U url and method");
         right_text!("-h response header");
+        key_value!("Turn off a flag, for example one of an alias:", "--no-<flag>");
+        right_text!("--no-timing, --no-verbose, --no-pager...");
+        right_text!("-no-hb hide the response header and body");
     };
 }
 macro_rules! headers {
     () => {
         println!("HEADERS:");
         key_value!("List of key:value space-separated", "<key:value>...");
+        flags!("Set a header that can't be written as key:value (e.g. a value with '=')", "raw-header=<key:value>");
     };
 }
 macro_rules! body {
@@ -115,12 +151,18 @@ macro_rules! body {
     };
 }
 
-#[cfg(feature = "alias")]
 macro_rules! subcommand {
     () => {
         println!("SUBCOMMAND:");
-        key_value!("Manage aliases", COMMAND_ALIAS);
-        try_help_alias!();
+        #[cfg(feature = "alias")]
+        {
+            key_value!("Manage aliases", COMMAND_ALIAS);
+            try_help_alias!();
+        }
+        key_value!("Convert a curl command line, then send the request or print it", COMMAND_FROM_CURL);
+        right_text!(format!("try '{} {} --help' for more information", rh_name!(), COMMAND_FROM_CURL));
+        key_value!("Replay the requests of an HTTP Archive (HAR) file", COMMAND_HAR);
+        right_text!(format!("try '{} {} --help' for more information", rh_name!(), COMMAND_HAR));
     };
 }
 
@@ -145,12 +187,9 @@ pub fn show() {
     }
     text!(format!("{} --help | -h", rh_name!()));
     text!(format!("{} --version", rh_name!()));
-    #[cfg(feature = "alias")]
-    {
-        newline!();
-        text!(format!("{} [SUBCOMMAND] [options]", rh_name!()));
-        text!(format!("{} [SUBCOMMAND] --help | -h", rh_name!()));
-    }
+    newline!();
+    text!(format!("{} [SUBCOMMAND] [options]", rh_name!()));
+    text!(format!("{} [SUBCOMMAND] --help | -h", rh_name!()));
 
     #[cfg(feature = "alias")]
     {
@@ -166,10 +205,7 @@ pub fn show() {
     newline!();
     body!();
     newline!();
-    #[cfg(feature = "alias")]
-    {
-        subcommand!();
-        newline!();
-    }
+    subcommand!();
+    newline!();
     thanks!();
 }
@@ -7,10 +7,17 @@ use super::debug;
 use super::{Command, DonePtr, Result};
 use crate::core::Args;
 use crate::core::Mode;
+use crate::core::PagerMode;
+use crate::core::Workspace;
 use crate::parser;
 use crate::request;
+use crate::request::timing::Timer;
+use crate::request::tls::TlsRecorder;
 use crate::shell::os::OsDirs;
+use crate::shell::warning::WarningRender;
 use crate::shell::Shell;
+use crate::theme;
+use render::CurlRender;
 use std::io::Write;
 
 pub struct HttpCommand;
@@ -18,21 +25,78 @@ pub struct HttpCommand;
 impl<OD: OsDirs, O: Write, E: Write> Command<OD, O, E> for HttpCommand {
     fn execute(&self, shell: &mut Shell<OD, O, E>, args: &mut Args, _: DonePtr) -> Result<()> {
         let ws = parser::execute(args)?;
-        match ws.mode() {
-            Mode::Help => help::show(),
-            Mode::Version => version::show(),
-            Mode::Debug => debug::show(),
-            Mode::Run => {
-                {
-                    let mut headers = ws.headers.borrow_mut();
-                    request::headers::upgrade(&ws, &mut headers);
+        execute_workspace(shell, ws)
+    }
+}
+
+// Also for the workspaces built by the other commands (e.g. from-curl or har), so that they have the same colours and theme
+pub fn execute_workspace<OD: OsDirs, O: Write, E: Write>(shell: &mut Shell<OD, O, E>, mut ws: Workspace) -> Result<()> {
+    if ws.flags.no_color {
+        shell.disable_err_colors();
+    }
+    if let Some(name) = ws.theme_name.as_deref() {
+        ws.theme = theme::load(shell.os_dirs(), name)?;
+    }
+    run(shell, &ws)
+}
+
+fn run<OD: OsDirs, O: Write, E: Write>(shell: &mut Shell<OD, O, E>, ws: &Workspace) -> Result<()> {
+    match ws.mode() {
+        Mode::Help => help::show(),
+        Mode::Version => version::show(),
+        Mode::Debug => debug::show(),
+        Mode::Curl => {
+            upgrade_headers(ws);
+            let headers = ws.headers.borrow();
+            let rf = CurlRender::new(ws, &headers, 0);
+            shell.out(rf)?;
+        }
+        Mode::Run => {
+            warn_if_insecure(shell, ws)?;
+            upgrade_headers(ws);
+            let headers = ws.headers.borrow();
+            let req_number = 0u8;
+            let timer = (ws.flags.show_timing || ws.flags.as_envelope || ws.har_file.is_some()).then(Timer::start);
+            let recorder = ws.flags.show_tls_info.then(TlsRecorder::default);
+            let response = request::execute(ws, req_number, &headers, timer.as_ref(), recorder.as_ref());
+            let tls_info = recorder.and_then(|recorder| recorder.info());
+            let response = match response {
+                Ok(response) => response,
+                Err(err) => {
+                    // The certificates are what we need to understand a TLS error
+                    if let Some(tls_info) = tls_info {
+                        output::render_tls_info(shell, ws, &tls_info)?;
+                    }
+                    return Err(err);
                 }
-                let headers = ws.headers.borrow();
-                let req_number = 0u8;
-                let response = request::execute(&ws, req_number, &headers)?;
-                output::render(shell, &ws, req_number, response)?;
+            };
+            if !ws.output_redirected && ws.flags.pager != PagerMode::Never {
+                shell.enable_pager(ws.flags.pager == PagerMode::Always);
             }
+            let rendered = output::render(shell, ws, req_number, response, timer, tls_info.as_ref());
+            // What has been rendered is shown even if there is an error
+            shell.show_pager()?;
+            rendered?;
         }
-        Ok(())
     }
+    Ok(())
+}
+
+// Printed every time, so an insecure flag can't go unnoticed in an alias
+fn warn_if_insecure<OD: OsDirs, O: Write, E: Write>(shell: &mut Shell<OD, O, E>, ws: &Workspace) -> Result<()> {
+    let message = if ws.flags.insecure {
+        "the TLS certificates are not verified (--insecure), the connection is not secure"
+    } else if ws.flags.insecure_hostname {
+        "the TLS certificate hostname is not verified (--insecure-hostname), the connection is not secure"
+    } else {
+        return Ok(());
+    };
+    let style_enabled = shell.err_colors();
+    shell.err(WarningRender::new(message, style_enabled))?;
+    Ok(())
+}
+
+fn upgrade_headers(ws: &Workspace) {
+    let mut headers = ws.headers.borrow_mut();
+    request::headers::upgrade(ws, &mut headers);
 }
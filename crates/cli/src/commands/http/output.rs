@@ -1,35 +1,122 @@
+use super::render::CookieRender;
+use super::render::EnvelopeRender;
+use super::render::ExplainRender;
+use super::render::FilterRender;
 use super::render::RequestRender;
 use super::render::ResponseRender;
+use super::render::TimingRender;
+use super::render::TlsRender;
+use crate::commands::har::archive;
+use crate::core::OutputFormat;
 use crate::core::Result;
 use crate::core::Workspace;
+use crate::request::charset;
+use crate::request::date;
+use crate::request::timing::Timer;
+use crate::request::tls::TlsInfo;
 use crate::request::Response;
+use crate::shell::hexdump::HexdumpRender;
 use crate::shell::os::OsDirs;
 use crate::shell::Shell;
-use std::borrow::Borrow;
-use std::cell::RefCell;
+use serde_json::Value;
 use std::io;
 use std::io::Read;
 use std::io::Write;
 
-pub fn render<OD: OsDirs, O: Write, E: Write>(shell: &mut Shell<OD, O, E>, ws: &Workspace, _req_number: u8, response: Response) -> Result<()> {
-    if ws.output_redirected && !ws.flags.borrow().use_color {
-        render_raw_content(ws, RefCell::new(response))?;
+pub fn render<OD: OsDirs, O: Write, E: Write>(
+    shell: &mut Shell<OD, O, E>,
+    ws: &Workspace,
+    req_number: u8,
+    mut response: Response,
+    timer: Option<Timer>,
+    tls_info: Option<&TlsInfo>,
+) -> Result<()> {
+    let mut body = Vec::new();
+    response.read_to_end(&mut body)?;
+    let timing = timer.map(|timer| timer.finish(body.len()));
+    if let Some(path) = ws.har_file.as_deref() {
+        archive::append(path, archive::entry(ws, req_number, &response, &body, timing.as_ref()))?;
+    }
+
+    if ws.flags.as_envelope {
+        // A single JSON document for the scripts, instead of the renders for the humans
+        let theme = ws.theme.response();
+        shell.out(EnvelopeRender::new(ws, req_number, &response, &body, timing.as_ref(), theme.as_data(), ws.flags.use_color))?;
+        return Ok(());
+    }
+    // The request may be timed for the HAR file only
+    let timing = timing.filter(|_| ws.flags.show_timing);
+
+    if ws.output_redirected && !ws.flags.use_color && !ws.flags.use_format {
+        let converted = ws.filter.is_some() || ws.flags.as_table || ws.flags.output_format != OutputFormat::Json;
+        // The body is piped unchanged, it's only decoded to be converted
+        let json = if converted { parse_json(ws, &response, &body) } else { None };
+        match json {
+            _ if ws.flags.as_hexdump => {
+                let theme = ws.theme.response();
+                shell.out(HexdumpRender::new(&body, ws.hexdump_limit, theme.as_data(), false))?;
+            }
+            Some(json) => {
+                let values = match ws.filter.as_ref() {
+                    Some(filter) => filter.apply(&json),
+                    None => vec![&json],
+                };
+                let theme = ws.theme.response();
+                shell.out(FilterRender::new(ws, values, theme.as_ref(), false))?;
+            }
+            None => render_raw_content(&body)?,
+        }
+        // The body may be piped somewhere else, the details go to stderr
+        if let Some(tls_info) = tls_info {
+            render_tls_info(shell, ws, tls_info)?;
+        }
+        if let Some(timing) = timing.as_ref() {
+            let theme = ws.theme.response();
+            let style_enabled = shell.err_colors();
+            shell.err(TimingRender::new(timing, theme.as_ref(), ws.flags.timing_as_json, style_enabled))?;
+        }
+        if ws.flags.explain {
+            let theme = ws.theme.response();
+            let style_enabled = shell.err_colors();
+            shell.err(ExplainRender::new(response.status(), response.headers(), theme.as_ref(), style_enabled))?;
+        }
+        if ws.flags.show_cookies {
+            let theme = ws.theme.response();
+            let style_enabled = shell.err_colors();
+            let https = response.url().scheme() == "https";
+            shell.err(CookieRender::new(response.headers(), https, date::now(), None, theme.as_ref(), style_enabled))?;
+        }
     } else {
-        let style_enabled = shell.enable_colors();
+        let style_enabled = ws.flags.use_color;
 
         let headers = ws.headers.borrow();
         let rf = RequestRender::new(ws, &headers, ws.theme.as_ref(), style_enabled);
         shell.out(rf)?;
 
-        let rf = ResponseRender::new(ws, RefCell::new(response), ws.theme.as_ref(), style_enabled);
+        if let Some(tls_info) = tls_info {
+            let theme = ws.theme.response();
+            shell.out(TlsRender::new(tls_info, theme.as_ref(), style_enabled))?;
+        }
+
+        let rf = ResponseRender::new(ws, &response, &body, timing.as_ref(), ws.theme.as_ref(), style_enabled);
         shell.out(rf)?;
     }
     Ok(())
 }
 
-fn render_raw_content(_args: &Workspace, response: RefCell<Response>) -> io::Result<()> {
-    let mut bytes = Vec::new();
-    let mut response = response.borrow_mut();
-    response.read_to_end(&mut bytes)?;
-    io::stdout().write_all(&bytes)
+pub fn render_tls_info<OD: OsDirs, O: Write, E: Write>(shell: &mut Shell<OD, O, E>, ws: &Workspace, tls_info: &TlsInfo) -> Result<()> {
+    let style_enabled = shell.err_colors();
+    let theme = ws.theme.response();
+    shell.err(TlsRender::new(tls_info, theme.as_ref(), style_enabled))?;
+    Ok(())
+}
+
+fn parse_json(ws: &Workspace, response: &Response, body: &[u8]) -> Option<Value> {
+    let decoded = charset::decode(body, ws.charset.or_else(|| charset::from_headers(response.headers())));
+    let text = decoded.as_ref().map_or(body, |text| text.as_bytes());
+    serde_json::from_slice::<Value>(text).ok()
+}
+
+fn render_raw_content(body: &[u8]) -> io::Result<()> {
+    io::stdout().write_all(body)
 }
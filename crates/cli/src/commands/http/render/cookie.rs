@@ -0,0 +1,122 @@
+use super::{CookieRender, Render};
+use crate::request::cookie::{self, Cookie};
+use crate::request::HeaderMap;
+use crate::shell::table::TableRender;
+use crate::shell::warning::WarningRender;
+use crate::theme::ResponseTheme;
+use reqwest::header::SET_COOKIE;
+use serde_json::{json, Map, Value};
+use std::io::{Result, Write};
+
+const COLUMNS: [&str; 8] = ["name", "value", "domain", "path", "secure", "httponly", "samesite", "expires"];
+
+impl<'a> CookieRender<'a> {
+    pub fn new(headers: &'a HeaderMap, https: bool, now: i64, max_width: Option<usize>, theme: &'a dyn ResponseTheme, style_enabled: bool) -> Self {
+        Self {
+            headers,
+            https,
+            now,
+            max_width,
+            theme,
+            style_enabled,
+        }
+    }
+}
+
+impl<'a> Render for CookieRender<'a> {
+    #[inline]
+    fn write<W>(&self, writer: &mut W) -> Result<()>
+    where
+        W: Write,
+    {
+        let cookies: Vec<Cookie> = self
+            .headers
+            .get_all(SET_COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .filter_map(|value| cookie::parse(value, self.now))
+            .collect();
+        if cookies.is_empty() {
+            return Ok(());
+        }
+
+        // One row per cookie, the attributes that aren't set are empty and the expiry is last because it's the longest
+        let rows: Vec<Value> = cookies.iter().map(|cookie| self.row(cookie)).collect();
+        let rows: Vec<&Value> = rows.iter().collect();
+        let columns: Vec<String> = COLUMNS.iter().map(|column| column.to_string()).collect();
+        if let Some(table) = TableRender::new(&rows, &columns, self.max_width, self.theme.as_data(), self.style_enabled) {
+            table.write(writer)?;
+            self.write_newline(writer)?;
+        }
+
+        for cookie in cookies.iter() {
+            for warning in cookie.warnings(self.https) {
+                WarningRender::new(format!("{}: {}", cookie.name, warning), self.style_enabled).write(writer)?;
+            }
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn is_style_active(&self) -> bool {
+        self.style_enabled
+    }
+}
+
+impl<'a> CookieRender<'a> {
+    fn row(&self, cookie: &Cookie) -> Value {
+        let mut row = Map::new();
+        row.insert("name".into(), json!(cookie.name));
+        row.insert("value".into(), json!(cookie.value));
+        if let Some(domain) = cookie.domain.as_ref() {
+            row.insert("domain".into(), json!(domain));
+        }
+        if let Some(path) = cookie.path.as_ref() {
+            row.insert("path".into(), json!(path));
+        }
+        row.insert("expires".into(), json!(cookie.expiry(self.now)));
+        row.insert("secure".into(), json!(cookie.secure));
+        row.insert("httponly".into(), json!(cookie.http_only));
+        if let Some(same_site) = cookie.same_site.as_ref() {
+            row.insert("samesite".into(), json!(same_site));
+        }
+        Value::Object(row)
+    }
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{CookieRender, Render};
+    use crate::request::HeaderMap;
+    use crate::theme::default::DefaultReponseTheme;
+    use reqwest::header::{HeaderValue, SET_COOKIE};
+
+    #[test]
+    fn cookies() {
+        let mut headers = HeaderMap::new();
+        headers.append(SET_COOKIE, HeaderValue::from_static("id=a3fWa; Max-Age=3600; Path=/; Secure; HttpOnly"));
+        headers.append(SET_COOKIE, HeaderValue::from_static("lang=en; SameSite=None"));
+        let mut writer = Vec::new();
+        CookieRender::new(&headers, true, 0, None, &DefaultReponseTheme {}, false).write(&mut writer).unwrap();
+        assert_eq!(
+            String::from_utf8(writer).unwrap(),
+            "name  value  domain  path  secure  httponly  samesite  expires
+----  -----  ------  ----  ------  --------  --------  -----------------------------------
+id    a3fWa          /     true    true                1970-01-01 01:00:00 UTC (in 1 hour)
+lang  en                   false   false     None      session
+Warning: lang: SameSite=None without Secure, the browsers reject the cookie
+"
+        );
+    }
+
+    #[test]
+    fn no_cookies() {
+        let mut writer = Vec::new();
+        CookieRender::new(&HeaderMap::new(), true, 0, None, &DefaultReponseTheme {}, false)
+            .write(&mut writer)
+            .unwrap();
+        assert!(writer.is_empty());
+    }
+}
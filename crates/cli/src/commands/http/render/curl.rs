@@ -0,0 +1,122 @@
+use super::{CurlRender, Render};
+use crate::core::Workspace;
+use crate::request::body::build_body;
+use crate::request::{HeaderMap, Method};
+use crate::shell::quote::quote;
+use std::io::{Result, Write};
+
+const CURL: &str = "curl";
+
+impl<'a> CurlRender<'a> {
+    pub fn new(workspace: &'a Workspace, headers: &'a HeaderMap, req_number: u8) -> Self {
+        Self {
+            workspace,
+            headers,
+            req_number: req_number as usize,
+        }
+    }
+}
+
+impl<'a> Render for CurlRender<'a> {
+    #[inline]
+    fn write<W>(&self, writer: &mut W) -> Result<()>
+    where
+        W: Write,
+    {
+        let ws = self.workspace;
+        let body = build_body(ws);
+
+        writer.write_all(CURL.as_bytes())?;
+        // curl can't skip only the hostname verification, so --insecure-hostname is exported as -k too
+        if ws.flags.insecure || ws.flags.insecure_hostname {
+            writer.write_all(b" -k")?;
+        }
+        if !is_implicit_method(&ws.method, body.is_some()) {
+            self.write_arg(writer, "-X", ws.method.as_str())?;
+        }
+        if let Some(url) = ws.urls.get(self.req_number) {
+            writer.write_all(b" ")?;
+            writer.write_all(quote(url).as_bytes())?;
+        }
+        for (key, value) in self.headers.iter() {
+            let header = format!("{}: {}", key.as_str(), String::from_utf8_lossy(value.as_bytes()));
+            self.write_arg(writer, "-H", &header)?;
+        }
+        if let Some(body) = body {
+            self.write_arg(writer, "--data-raw", &body)?;
+        }
+        if let Some(cafile) = ws.certificate_authority_file.as_ref() {
+            self.write_arg(writer, "--cacert", cafile)?;
+        }
+        self.write_newline(writer)
+    }
+
+    #[inline]
+    fn is_style_active(&self) -> bool {
+        false
+    }
+}
+
+impl<'a> CurlRender<'a> {
+    #[inline]
+    fn write_arg<W: Write>(&self, writer: &mut W, option: &str, value: &str) -> Result<()> {
+        writer.write_all(b" ")?;
+        writer.write_all(option.as_bytes())?;
+        writer.write_all(b" ")?;
+        writer.write_all(quote(value).as_bytes())
+    }
+}
+
+// curl defaults to GET, or to POST as soon as there is a body
+fn is_implicit_method(method: &Method, has_body: bool) -> bool {
+    if has_body {
+        method == Method::POST
+    } else {
+        method == Method::GET
+    }
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{is_implicit_method, CurlRender, Method, Render};
+    use crate::parser;
+    use crate::request::headers::upgrade;
+
+    #[test]
+    fn implicit_method() {
+        assert!(is_implicit_method(&Method::GET, false));
+        assert!(is_implicit_method(&Method::POST, true));
+        assert!(!is_implicit_method(&Method::POST, false));
+        assert!(!is_implicit_method(&Method::GET, true));
+        assert!(!is_implicit_method(&Method::PUT, true));
+    }
+
+    #[test]
+    fn curl_command() {
+        let args: Vec<String> = [
+            "PUT",
+            "https://example.com/search?q=rh&page=2",
+            "User-Agent:rh",
+            "name=O'Brien",
+            "--cafile=/etc/ssl/ca.pem",
+            "--insecure-hostname",
+        ]
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect();
+        let ws = parser::execute_without_stdin(&args).unwrap();
+        let mut headers = ws.headers.borrow_mut();
+        upgrade(&ws, &mut headers);
+
+        let mut writer = Vec::new();
+        CurlRender::new(&ws, &headers, 0).write(&mut writer).unwrap();
+        assert_eq!(
+            String::from_utf8(writer).unwrap(),
+            "curl -k -X PUT 'https://example.com/search?q=rh&page=2' \
+             -H 'user-agent: rh' -H 'content-type: application/json' -H 'accept: application/json' \
+             --data-raw '{\"name\":\"O'\\''Brien\"}' --cacert /etc/ssl/ca.pem\n"
+        );
+    }
+}
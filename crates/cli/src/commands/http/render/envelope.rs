@@ -0,0 +1,141 @@
+use super::timing::to_json;
+use super::{EnvelopeRender, Render};
+use crate::core::Workspace;
+use crate::request::body::build_body;
+use crate::request::charset::{self, Charset};
+use crate::request::timing::Timing;
+use crate::request::{HeaderMap, Response};
+use crate::shell::json::JsonRender;
+use crate::theme::DataTheme;
+use content_inspector::inspect;
+use serde_json::{json, Map, Value};
+use std::io::{Result, Write};
+
+impl<'a> EnvelopeRender<'a> {
+    pub fn new(
+        workspace: &'a Workspace,
+        req_number: u8,
+        response: &'a Response,
+        body: &'a [u8],
+        timing: Option<&'a Timing>,
+        theme: &'a dyn DataTheme,
+        style_enabled: bool,
+    ) -> Self {
+        Self {
+            workspace,
+            req_number,
+            response,
+            body,
+            timing,
+            theme,
+            style_enabled,
+        }
+    }
+}
+
+impl<'a> Render for EnvelopeRender<'a> {
+    #[inline]
+    fn write<W>(&self, writer: &mut W) -> Result<()>
+    where
+        W: Write,
+    {
+        let envelope = self.envelope();
+        // One line per request if the output is redirected, so that it can be read as JSON Lines
+        JsonRender::new(&envelope, !self.workspace.flags.use_format, false, self.theme, self.style_enabled).write(writer)?;
+        self.write_newline(writer)
+    }
+
+    #[inline]
+    fn is_style_active(&self) -> bool {
+        self.style_enabled
+    }
+}
+
+impl<'a> EnvelopeRender<'a> {
+    fn envelope(&self) -> Value {
+        let ws = self.workspace;
+        let response = self.response;
+
+        let mut request = Map::new();
+        request.insert("method".into(), json!(ws.method.as_str()));
+        request.insert("url".into(), json!(ws.urls[self.req_number as usize]));
+        request.insert("headers".into(), headers(&ws.headers.borrow()));
+        if let Some(body) = build_body(ws).filter(|body| !body.is_empty()) {
+            insert_body(&mut request, body.as_bytes(), None);
+        }
+
+        let mut reply = Map::new();
+        reply.insert("status".into(), json!(response.status().as_u16()));
+        reply.insert("reason".into(), json!(response.status().canonical_reason()));
+        reply.insert("version".into(), json!(format!("{:?}", response.version())));
+        reply.insert("url".into(), json!(response.url().as_str()));
+        reply.insert("headers".into(), headers(response.headers()));
+        let charset = ws.charset.or_else(|| charset::from_headers(response.headers()));
+        insert_body(&mut reply, self.body, charset);
+
+        json!({
+            "request": request,
+            "response": reply,
+            "timing": self.timing.map(to_json),
+        })
+    }
+}
+
+// A header with several values (e.g. Set-Cookie) is an array
+pub fn headers(headers: &HeaderMap) -> Value {
+    let mut map = Map::new();
+    for name in headers.keys() {
+        let mut values: Vec<Value> = headers.get_all(name).iter().map(|value| json!(String::from_utf8_lossy(value.as_bytes()))).collect();
+        let value = if values.len() == 1 { values.remove(0) } else { Value::Array(values) };
+        map.insert(name.to_string(), value);
+    }
+    Value::Object(map)
+}
+
+// The body is parsed if it's JSON, a text otherwise, or base64 if it's binary
+fn insert_body(map: &mut Map<String, Value>, bytes: &[u8], charset: Option<Charset>) {
+    let decoded = charset::decode(bytes, charset);
+    let (body, encoding) = match decoded {
+        None if inspect(bytes).is_binary() => (json!(base64::encode(bytes)), "base64"),
+        decoded => {
+            let text = decoded.unwrap_or_else(|| String::from_utf8_lossy(bytes));
+            match serde_json::from_str::<Value>(&text) {
+                Ok(value) => (value, "json"),
+                Err(_) => (json!(text), "text"),
+            }
+        }
+    };
+    map.insert("body".into(), body);
+    map.insert("encoding".into(), json!(encoding));
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{headers, insert_body};
+    use crate::request::HeaderMap;
+    use reqwest::header::{HeaderValue, CONTENT_TYPE, SET_COOKIE};
+    use serde_json::{json, Map, Value};
+
+    #[test]
+    fn header_values() {
+        let mut map = HeaderMap::new();
+        map.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        map.append(SET_COOKIE, HeaderValue::from_static("a=1"));
+        map.append(SET_COOKIE, HeaderValue::from_static("b=2"));
+        assert_eq!(headers(&map), json!({"content-type": "application/json", "set-cookie": ["a=1", "b=2"]}));
+    }
+
+    #[test]
+    fn bodies() {
+        let body = |bytes: &[u8]| {
+            let mut map = Map::new();
+            insert_body(&mut map, bytes, None);
+            Value::Object(map)
+        };
+        assert_eq!(body(b"{\"id\": 1}"), json!({"body": {"id": 1}, "encoding": "json"}));
+        assert_eq!(body(b"Hello"), json!({"body": "Hello", "encoding": "text"}));
+        assert_eq!(body(b"\x89PNG\r\n\x1a\n\x00\x00"), json!({"body": "iVBORw0KGgoAAA==", "encoding": "base64"}));
+    }
+}
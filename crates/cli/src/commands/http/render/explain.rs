@@ -0,0 +1,52 @@
+use super::response::status_style;
+use super::{ExplainRender, Render};
+use crate::request::explain;
+use crate::request::header::StandardHeader;
+use crate::request::{HeaderMap, StatusCode};
+use crate::theme::style::Style;
+use crate::theme::ResponseTheme;
+use std::io::{Result, Write};
+
+const PREFIX: &[u8] = b"# ";
+
+impl<'a> ExplainRender<'a> {
+    pub fn new(status: StatusCode, headers: &'a HeaderMap, theme: &'a dyn ResponseTheme, style_enabled: bool) -> Self {
+        Self {
+            status,
+            headers,
+            theme,
+            style_enabled,
+        }
+    }
+}
+
+impl<'a> Render for ExplainRender<'a> {
+    #[inline]
+    fn write<W>(&self, writer: &mut W) -> Result<()>
+    where
+        W: Write,
+    {
+        let status = self.status;
+        let label = format!("{} {}", status.as_str(), status.canonical_reason().unwrap_or("Unknown"));
+        self.write_line(writer, &label, &status_style(self.theme, status), explain::status(status))?;
+        for (name, explanation) in explain::headers(self.headers) {
+            let style = self.theme.as_header().header_name(name.is_standard());
+            self.write_line(writer, name.as_str(), &style, &explanation)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn is_style_active(&self) -> bool {
+        self.style_enabled
+    }
+}
+
+impl<'a> ExplainRender<'a> {
+    #[inline]
+    fn write_line<W: Write>(&self, writer: &mut W, label: &str, style: &Style, explanation: &str) -> Result<()> {
+        writer.write_all(PREFIX)?;
+        self.write_with_style(writer, label.as_bytes(), style)?;
+        writeln!(writer, ": {}", explanation)
+    }
+}
@@ -0,0 +1,51 @@
+use super::{FilterRender, Render, ValueRender};
+use crate::core::{OutputFormat, Workspace};
+use crate::shell::table::TableRender;
+use crate::theme::ResponseTheme;
+use serde_json::Value;
+use std::io::{Result, Write};
+
+impl<'a> FilterRender<'a> {
+    pub fn new(workspace: &'a Workspace, values: Vec<&'a Value>, theme: &'a dyn ResponseTheme, style_enabled: bool) -> Self {
+        Self {
+            workspace,
+            values,
+            theme,
+            style_enabled,
+        }
+    }
+}
+
+impl<'a> Render for FilterRender<'a> {
+    #[inline]
+    fn write<W: Write>(&self, writer: &mut W) -> Result<()> {
+        let flags = self.workspace.flags;
+        if flags.as_table {
+            if let Some(table) = TableRender::new(
+                &self.values,
+                &self.workspace.table_columns,
+                self.workspace.output_width(),
+                self.theme.as_data(),
+                self.style_enabled,
+            ) {
+                table.write(writer)?;
+                return self.write_newline(writer);
+            }
+        }
+        let raw = !flags.use_format && !self.style_enabled && flags.output_format == OutputFormat::Json;
+        for value in self.values.iter() {
+            match value {
+                // Raw strings like 'jq -r' so that the values can be piped to other commands
+                Value::String(value) if raw => writer.write_all(value.as_bytes())?,
+                value => ValueRender::new(self.workspace, value, self.theme, self.style_enabled).write(writer)?,
+            }
+            self.write_newline(writer)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn is_style_active(&self) -> bool {
+        self.style_enabled
+    }
+}
@@ -1,10 +1,15 @@
-use super::{HeaderRender, Render};
+use super::{HeaderRender, JwtRender, Render};
+use crate::request::date;
 use crate::request::header::StandardHeader;
+use crate::request::jwt::{self, Jwt};
 use crate::request::HeaderMap;
-use crate::theme::HeaderTheme;
+use crate::shell::wrap::wrap;
+use crate::theme::{DataTheme, HeaderTheme};
 use crate::{core::Workspace, theme::DirectionTheme};
 use std::io::{Result, Write};
 
+const JWT_INDENT: &[u8] = b"  ";
+
 impl<'a> HeaderRender<'a> {
     pub fn new(
         workspace: &'a Workspace,
@@ -12,6 +17,7 @@ impl<'a> HeaderRender<'a> {
         header_theme: &'a dyn HeaderTheme,
         direction_theme: &'a dyn DirectionTheme,
         direction_symbol: &'a [u8],
+        data_theme: &'a dyn DataTheme,
         style_enabled: bool,
     ) -> Self {
         Self {
@@ -20,6 +26,7 @@ impl<'a> HeaderRender<'a> {
             header_theme,
             direction_theme,
             direction_symbol,
+            data_theme,
             style_enabled,
         }
     }
@@ -34,7 +41,13 @@ impl<'a> Render for HeaderRender<'a> {
         let flags = self.workspace.flags;
         let header_theme = self.header_theme;
 
-        for (key, value) in self.headers.iter() {
+        let mut headers: Vec<_> = self.headers.iter().collect();
+        if flags.sort_headers {
+            // The sort is stable, the values of a same header stay in the received order
+            headers.sort_by(|(a, _), (b, _)| a.as_str().cmp(b.as_str()));
+        }
+
+        for (key, value) in headers {
             let is_standard = key.is_standard();
             let key_style = header_theme.header_name(is_standard);
             let key = key.as_str();
@@ -44,8 +57,25 @@ impl<'a> Render for HeaderRender<'a> {
             }
             self.write_with_style(writer, key.as_bytes(), &key_style)?;
             self.write_with_style(writer, ": ".as_bytes(), &key_style)?;
-            self.write_with_style(writer, value.to_str().unwrap_or("No value").as_bytes(), &header_theme.header_value(is_standard))?;
-            self.write_newline(writer)?;
+            // The value is wrapped and aligned after the name
+            let value = value.to_str().unwrap_or("No value");
+            let indent = key.chars().count() + 2;
+            let width = self.workspace.output_width().map_or(0, |width| width.saturating_sub(indent + self.direction_width()));
+            for (index, line) in wrap(value, width).into_iter().enumerate() {
+                if index > 0 {
+                    if flags.show_direction {
+                        self.write_direction(writer, is_standard)?;
+                    }
+                    writer.write_all(" ".repeat(indent).as_bytes())?;
+                }
+                self.write_with_style(writer, line.as_bytes(), &header_theme.header_value(is_standard))?;
+                self.write_newline(writer)?;
+            }
+            if flags.decode_jwt {
+                for (_, jwt) in jwt::find(jwt_value(key, value)) {
+                    self.write_jwt(writer, &jwt, is_standard)?;
+                }
+            }
         }
         Ok(())
     }
@@ -61,4 +91,40 @@ impl<'a> HeaderRender<'a> {
     fn write_direction<W: Write>(&self, writer: &mut W, is_standard: bool) -> Result<()> {
         self.write_with_style(writer, self.direction_symbol, &self.direction_theme.direction(is_standard))
     }
+
+    // The decoded JWT is indented below its header
+    fn write_jwt<W: Write>(&self, writer: &mut W, jwt: &Jwt, is_standard: bool) -> Result<()> {
+        let mut buffer = Vec::new();
+        JwtRender::new(jwt, None, date::now(), self.data_theme, self.style_enabled).write(&mut buffer)?;
+        for line in String::from_utf8_lossy(&buffer).lines() {
+            if self.workspace.flags.show_direction {
+                self.write_direction(writer, is_standard)?;
+            }
+            writer.write_all(JWT_INDENT)?;
+            writer.write_all(line.as_bytes())?;
+            self.write_newline(writer)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn direction_width(&self) -> usize {
+        if self.workspace.flags.show_direction {
+            self.direction_symbol.len()
+        } else {
+            0
+        }
+    }
+}
+
+// The headers with a JWT: Authorization with the Bearer scheme and Set-Cookie
+fn jwt_value<'v>(key: &str, value: &'v str) -> &'v str {
+    match key {
+        "authorization" => match value.split_once(' ') {
+            Some((scheme, token)) if scheme.eq_ignore_ascii_case("bearer") => token,
+            _ => "",
+        },
+        "set-cookie" => value,
+        _ => "",
+    }
 }
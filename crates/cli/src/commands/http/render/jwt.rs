@@ -0,0 +1,82 @@
+use super::{JwtRender, Render};
+use crate::request::jwt::Jwt;
+use crate::shell::json::JsonRender;
+use crate::theme::style::Color;
+use crate::theme::DataTheme;
+use std::io::{Result, Write};
+
+impl<'a> JwtRender<'a> {
+    pub fn new(jwt: &'a Jwt, path: Option<&'a str>, now: i64, theme: &'a dyn DataTheme, style_enabled: bool) -> Self {
+        Self {
+            jwt,
+            path,
+            now,
+            theme,
+            style_enabled,
+        }
+    }
+}
+
+impl<'a> Render for JwtRender<'a> {
+    #[inline]
+    fn write<W>(&self, writer: &mut W) -> Result<()>
+    where
+        W: Write,
+    {
+        let label = match self.path {
+            Some(path) => format!("JWT at {} (signature not verified):", path),
+            None => "JWT (signature not verified):".to_string(),
+        };
+        self.write_with_style(writer, label.as_bytes(), &self.theme.data_key())?;
+        self.write_newline(writer)?;
+        for value in [&self.jwt.header, &self.jwt.claims] {
+            JsonRender::new(value, false, false, self.theme, self.style_enabled).write(writer)?;
+            self.write_newline(writer)?;
+        }
+        for claim in self.jwt.time_claims(self.now) {
+            self.write_with_style(writer, format!("{}: ", claim.name).as_bytes(), &self.theme.data_key())?;
+            let style = if claim.is_warning { Color::Red.bold() } else { self.theme.data_number() };
+            self.write_with_style(writer, claim.description.as_bytes(), &style)?;
+            self.write_newline(writer)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn is_style_active(&self) -> bool {
+        self.style_enabled
+    }
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{JwtRender, Render};
+    use crate::request::jwt::Jwt;
+    use crate::theme::default::DefaultReponseTheme;
+    use serde_json::json;
+
+    #[test]
+    fn jwt() {
+        let jwt = Jwt {
+            header: json!({"alg": "none"}),
+            claims: json!({"sub": "rh", "exp": 1000}),
+        };
+        let mut writer = Vec::new();
+        JwtRender::new(&jwt, Some(".id_token"), 1060, &DefaultReponseTheme {}, false).write(&mut writer).unwrap();
+        assert_eq!(
+            String::from_utf8(writer).unwrap(),
+            "JWT at .id_token (signature not verified):
+{
+  \"alg\": \"none\"
+}
+{
+  \"sub\": \"rh\",
+  \"exp\": 1000
+}
+exp: 1970-01-01 00:16:40 UTC, expired 1 minute ago
+"
+        );
+    }
+}
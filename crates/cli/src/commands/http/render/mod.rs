@@ -1,12 +1,25 @@
+mod cookie;
+mod curl;
+mod envelope;
+mod explain;
+mod filter;
 mod header;
+mod jwt;
+mod problem;
 mod request;
 mod response;
+mod timing;
+mod tls;
+mod value;
 
 use crate::core::Workspace;
-use crate::request::{HeaderMap, Response};
+use crate::request::jwt::Jwt;
+use crate::request::timing::Timing;
+use crate::request::tls::TlsInfo;
+use crate::request::{HeaderMap, Response, StatusCode};
 use crate::shell::Render;
-use crate::theme::{DirectionTheme, HeaderTheme, Theme};
-use std::cell::RefCell;
+use crate::theme::{DataTheme, DirectionTheme, HeaderTheme, ResponseTheme, Theme};
+use serde_json::{Map, Value};
 
 pub const DIRECTION_REQUEST: &[u8] = b"> ";
 pub const DIRECTION_RESPONSE: &[u8] = b"< ";
@@ -21,7 +34,9 @@ pub struct RequestRender<'a> {
 
 pub struct ResponseRender<'a> {
     workspace: &'a Workspace,
-    response: RefCell<Response>,
+    response: &'a Response,
+    body: &'a [u8],
+    timing: Option<&'a Timing>,
     theme: &'a dyn Theme,
     style_enabled: bool,
 }
@@ -32,5 +47,80 @@ pub struct HeaderRender<'a> {
     header_theme: &'a dyn HeaderTheme,
     direction_theme: &'a dyn DirectionTheme,
     direction_symbol: &'a [u8],
+    data_theme: &'a dyn DataTheme,
     style_enabled: bool,
 }
+
+pub struct FilterRender<'a> {
+    workspace: &'a Workspace,
+    values: Vec<&'a Value>,
+    theme: &'a dyn ResponseTheme,
+    style_enabled: bool,
+}
+
+pub struct ValueRender<'a> {
+    workspace: &'a Workspace,
+    value: &'a Value,
+    theme: &'a dyn ResponseTheme,
+    style_enabled: bool,
+}
+
+pub struct EnvelopeRender<'a> {
+    workspace: &'a Workspace,
+    req_number: u8,
+    response: &'a Response,
+    body: &'a [u8],
+    timing: Option<&'a Timing>,
+    theme: &'a dyn DataTheme,
+    style_enabled: bool,
+}
+
+pub struct TimingRender<'a> {
+    timing: &'a Timing,
+    theme: &'a dyn ResponseTheme,
+    as_json: bool,
+    style_enabled: bool,
+}
+
+pub struct ExplainRender<'a> {
+    status: StatusCode,
+    headers: &'a HeaderMap,
+    theme: &'a dyn ResponseTheme,
+    style_enabled: bool,
+}
+
+pub struct ProblemRender<'a> {
+    problem: &'a Map<String, Value>,
+    status: StatusCode,
+    theme: &'a dyn ResponseTheme,
+    style_enabled: bool,
+}
+
+pub struct JwtRender<'a> {
+    jwt: &'a Jwt,
+    path: Option<&'a str>,
+    now: i64,
+    theme: &'a dyn DataTheme,
+    style_enabled: bool,
+}
+
+pub struct CookieRender<'a> {
+    headers: &'a HeaderMap,
+    https: bool,
+    now: i64,
+    max_width: Option<usize>,
+    theme: &'a dyn ResponseTheme,
+    style_enabled: bool,
+}
+
+pub struct TlsRender<'a> {
+    tls_info: &'a TlsInfo,
+    theme: &'a dyn ResponseTheme,
+    style_enabled: bool,
+}
+
+pub struct CurlRender<'a> {
+    workspace: &'a Workspace,
+    headers: &'a HeaderMap,
+    req_number: usize,
+}
@@ -0,0 +1,144 @@
+use super::response::status_style;
+use super::{ProblemRender, Render};
+use crate::request::StatusCode;
+use crate::theme::ResponseTheme;
+use serde_json::{Map, Value};
+use std::io::{Result, Write};
+
+const PROBLEM_CONTENT_TYPE: &str = "application/problem+json";
+const MEMBERS: [&str; 3] = ["detail", "instance", "type"];
+
+// RFC 7807, for example: {"type":"https://example.com/out-of-credit","title":"You do not have enough credit","status":403}
+pub fn is_problem(content_type: &str) -> bool {
+    let media_type = content_type.split(';').next().unwrap_or_default().trim();
+    media_type.eq_ignore_ascii_case(PROBLEM_CONTENT_TYPE)
+}
+
+impl<'a> ProblemRender<'a> {
+    pub fn new(problem: &'a Map<String, Value>, status: StatusCode, theme: &'a dyn ResponseTheme, style_enabled: bool) -> Self {
+        Self {
+            problem,
+            status,
+            theme,
+            style_enabled,
+        }
+    }
+}
+
+impl<'a> Render for ProblemRender<'a> {
+    #[inline]
+    fn write<W>(&self, writer: &mut W) -> Result<()>
+    where
+        W: Write,
+    {
+        // The status of the problem is the one of the response if it's missing
+        let status = match self.problem.get("status").and_then(Value::as_u64) {
+            Some(status) => u16::try_from(status).ok().and_then(|status| StatusCode::from_u16(status).ok()).unwrap_or(self.status),
+            None => self.status,
+        };
+        let title = match self.problem.get("title") {
+            Some(title) => text(title),
+            None => status.canonical_reason().unwrap_or("Unknown").to_string(),
+        };
+        let summary = format!("Problem: {} ({})", title, status.as_str());
+        self.write_with_style(writer, summary.as_bytes(), &status_style(self.theme, status))?;
+        self.write_newline(writer)?;
+
+        for key in MEMBERS {
+            match self.problem.get(key) {
+                // The default type means that there is no more details than the status
+                Some(Value::String(value)) if key == "type" && value == "about:blank" => {}
+                Some(value) => self.write_member(writer, key, value)?,
+                None => {}
+            }
+        }
+        // The extension members, in the order of the response
+        for (key, value) in self.problem {
+            if key != "title" && key != "status" && !MEMBERS.contains(&key.as_str()) {
+                self.write_member(writer, key, value)?;
+            }
+        }
+        self.write_newline(writer)
+    }
+
+    #[inline]
+    fn is_style_active(&self) -> bool {
+        self.style_enabled
+    }
+}
+
+impl<'a> ProblemRender<'a> {
+    #[inline]
+    fn write_member<W: Write>(&self, writer: &mut W, key: &str, value: &Value) -> Result<()> {
+        writer.write_all(b"  ")?;
+        self.write_with_style(writer, format!("{}:", key).as_bytes(), &self.theme.secondary())?;
+        writeln!(writer, " {}", text(value))
+    }
+}
+
+// The strings without quotes, the other values as compact JSON
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{is_problem, ProblemRender, Render};
+    use crate::request::StatusCode;
+    use crate::theme::default::DefaultReponseTheme;
+    use serde_json::{json, Value};
+
+    fn render(problem: Value, status: StatusCode) -> String {
+        let mut writer = Vec::new();
+        ProblemRender::new(problem.as_object().unwrap(), status, &DefaultReponseTheme {}, false)
+            .write(&mut writer)
+            .unwrap();
+        String::from_utf8(writer).unwrap()
+    }
+
+    #[test]
+    fn content_type() {
+        assert!(is_problem("application/problem+json"));
+        assert!(is_problem("Application/Problem+JSON; charset=utf-8"));
+        assert!(!is_problem("application/json"));
+        assert!(!is_problem("application/problem+xml"));
+    }
+
+    #[test]
+    fn summary() {
+        let problem = json!({
+            "type": "https://example.com/probs/out-of-credit",
+            "title": "You do not have enough credit.",
+            "status": 403,
+            "detail": "Your current balance is 30, but that costs 50.",
+            "instance": "/account/12345/msgs/abc",
+            "balance": 30,
+            "accounts": ["/account/12345", "/account/67890"]
+        });
+        assert_eq!(
+            render(problem, StatusCode::BAD_REQUEST),
+            "Problem: You do not have enough credit. (403)
+  detail: Your current balance is 30, but that costs 50.
+  instance: /account/12345/msgs/abc
+  type: https://example.com/probs/out-of-credit
+  balance: 30
+  accounts: [\"/account/12345\",\"/account/67890\"]
+
+"
+        );
+    }
+
+    #[test]
+    fn minimal() {
+        let problem = json!({"type": "about:blank"});
+        assert_eq!(render(problem, StatusCode::NOT_FOUND), "Problem: Not Found (404)\n\n");
+
+        let problem = json!({"title": "Oops", "status": "500"});
+        assert_eq!(render(problem, StatusCode::BAD_GATEWAY), "Problem: Oops (502)\n\n");
+    }
+}
@@ -84,7 +84,9 @@ impl<'a> RequestRender<'a> {
             let flags = ws.flags;
             let items = ws.items.borrow();
             if ws.is_json() {
-                let json_render = JsonRender::new(&items as &Items, flags.show_request_compact, self.style_enabled);
+                let theme = self.theme.request();
+                let compact = flags.show_request_compact || !flags.use_format;
+                let json_render = JsonRender::new(&items as &Items, compact, flags.sort_json, theme.as_data(), self.style_enabled);
                 json_render.write(writer)?;
             } else {
                 let json_render = FormRender::new(&items as &Items, flags.show_request_compact, self.style_enabled);
@@ -103,7 +105,16 @@ impl<'a> RequestRender<'a> {
         let request_theme = self.theme.request();
         let header_theme = request_theme.as_header();
         let direction_theme = request_theme.as_direction();
-        let header_render = HeaderRender::new(self.workspace, self.headers, header_theme, direction_theme, DIRECTION_REQUEST, self.style_enabled);
+        let data_theme = request_theme.as_data();
+        let header_render = HeaderRender::new(
+            self.workspace,
+            self.headers,
+            header_theme,
+            direction_theme,
+            DIRECTION_REQUEST,
+            data_theme,
+            self.style_enabled,
+        );
         header_render.write(writer)
     }
 }
@@ -1,19 +1,29 @@
-use super::{HeaderRender, Render, ResponseRender, DIRECTION_RESPONSE};
-use crate::request::Response;
+use super::problem::is_problem;
+use super::{CookieRender, ExplainRender, FilterRender, HeaderRender, JwtRender, ProblemRender, Render, ResponseRender, TimingRender, ValueRender, DIRECTION_RESPONSE};
+use crate::core::{OutputFormat, Workspace};
+use crate::request::charset;
+use crate::request::date;
+use crate::request::jwt;
+use crate::request::timing::Timing;
+use crate::request::{Response, StatusCode};
 use crate::rh_name;
-use crate::shell::json::JsonRender;
-use crate::{core::Workspace, theme::Theme};
+use crate::shell::hexdump::HexdumpRender;
+use crate::shell::markup::{Markup, MarkupRender};
+use crate::shell::wrap::wrap;
+use crate::theme::style::Style;
+use crate::theme::{ResponseTheme, Theme};
 use content_inspector::inspect;
+use reqwest::header::CONTENT_TYPE;
 use serde_json::Value;
-use std::cell::RefCell;
-use std::io::Read;
 use std::io::{Result, Write};
 
 impl<'a> ResponseRender<'a> {
-    pub fn new(workspace: &'a Workspace, response: RefCell<Response>, theme: &'a dyn Theme, style_enabled: bool) -> Self {
+    pub fn new(workspace: &'a Workspace, response: &'a Response, body: &'a [u8], timing: Option<&'a Timing>, theme: &'a dyn Theme, style_enabled: bool) -> Self {
         Self {
             workspace,
             response,
+            body,
+            timing,
             theme,
             style_enabled,
         }
@@ -34,9 +44,19 @@ impl<'a> Render for ResponseRender<'a> {
             }
             self.write_version_and_status(writer)?;
         }
+        if let Some(timing) = self.timing {
+            self.write_timing(writer, timing)?;
+        }
+        if flags.explain {
+            let theme = self.theme.response();
+            ExplainRender::new(self.response.status(), self.response.headers(), theme.as_ref(), self.style_enabled).write(writer)?;
+        }
         if flags.show_response_headers {
             self.write_headers(writer)?;
         }
+        if flags.show_cookies {
+            self.write_cookies(writer)?;
+        }
         if flags.show_response_body {
             self.write_body(writer)?;
         }
@@ -49,6 +69,21 @@ impl<'a> Render for ResponseRender<'a> {
     }
 }
 
+// 2xx, 3xx, 4xx and 5xx statuses have their own style
+pub fn status_style(theme: &dyn ResponseTheme, status: StatusCode) -> Style {
+    if status.is_success() {
+        theme.status_success()
+    } else if status.is_redirection() {
+        theme.status_redirection()
+    } else if status.is_client_error() {
+        theme.status_client_error()
+    } else if status.is_server_error() {
+        theme.status_server_error()
+    } else {
+        theme.status()
+    }
+}
+
 impl<'a> ResponseRender<'a> {
     #[inline]
     fn write_direction<W: Write>(&self, writer: &mut W, is_standard: bool) -> Result<()> {
@@ -57,7 +92,7 @@ impl<'a> ResponseRender<'a> {
 
     #[inline]
     fn write_version_and_status<W: Write>(&self, writer: &mut W) -> Result<()> {
-        let response = &self.response.borrow();
+        let response = self.response;
         let status = response.status();
         let theme = self.workspace.theme.response();
 
@@ -65,7 +100,7 @@ impl<'a> ResponseRender<'a> {
         let message = format!("{:?} ", response.version());
         self.write_with_style(writer, message.as_bytes(), &style)?;
 
-        let style = theme.status();
+        let style = status_style(theme.as_ref(), status);
         self.write_with_style(writer, status.as_str().as_bytes(), &style)?;
         writer.write_all(b" ")?;
         self.write_with_style(writer, status.canonical_reason().unwrap_or("Unknown").as_bytes(), &style)?;
@@ -76,26 +111,132 @@ impl<'a> ResponseRender<'a> {
     fn write_body<W: Write>(&self, writer: &mut W) -> Result<()> {
         let ws = self.workspace;
         let flags = ws.flags;
-        let mut response = self.response.borrow_mut();
+        let bytes = self.body;
 
-        let mut bytes = Vec::new();
-        let size = response.read_to_end(&mut bytes).unwrap_or(0);
-        let content_type = inspect(&bytes);
-        if content_type.is_binary() {
-            self.write_binary_usage(writer, size)?;
+        if flags.as_hexdump {
+            // Each line ends with a new line
+            let theme = self.theme.response();
+            return HexdumpRender::new(bytes, ws.hexdump_limit.or(ws.max_body), theme.as_data(), self.style_enabled).write(writer);
+        }
+        let (bytes, more_bytes) = self.shown_body();
+        let decoded = charset::decode(bytes, ws.charset.or_else(|| charset::from_headers(self.response.headers())));
+        let mut jwts = Vec::new();
+        if decoded.is_none() && inspect(bytes).is_binary() {
+            self.write_binary_usage(writer, self.body.len())?;
         } else {
-            let body = String::from_utf8_lossy(&bytes);
-            match serde_json::from_str::<Value>(&body) {
-                Ok(json) => {
-                    let json_render = JsonRender::new(&json, flags.show_response_compact, self.style_enabled);
-                    json_render.write(writer)?;
+            let body = decoded.unwrap_or_else(|| String::from_utf8_lossy(bytes));
+            let json = serde_json::from_str::<Value>(&body).ok();
+            let theme = self.theme.response();
+            match (json, ws.filter.as_ref()) {
+                (Some(json), filter) if filter.is_some() || flags.as_table => {
+                    let values = match filter {
+                        Some(filter) => filter.apply(&json),
+                        None => vec![&json],
+                    };
+                    // Each value ends with a new line
+                    FilterRender::new(ws, values, theme.as_ref(), self.style_enabled).write(writer)?;
+                    return self.write_more_bytes(writer, more_bytes);
+                }
+                (Some(json), None) if flags.use_format || self.style_enabled || flags.output_format != OutputFormat::Json => {
+                    if let Some(problem) = json.as_object().filter(|_| self.is_problem()) {
+                        // The summary makes the error stand out before the details
+                        ProblemRender::new(problem, self.response.status(), theme.as_ref(), self.style_enabled).write(writer)?;
+                    }
+                    let value_render = ValueRender::new(ws, &json, theme.as_ref(), self.style_enabled);
+                    value_render.write(writer)?;
+                    if flags.decode_jwt {
+                        jwts = jwt::find_in_json(&json);
+                    }
                 }
-                Err(_) => {
-                    writer.write_all(body.as_bytes())?;
+                (None, _) if flags.use_format || self.style_enabled => match self.markup() {
+                    Some(markup) => {
+                        let markup_render = MarkupRender::new(&body, markup, flags.show_response_compact, flags.use_format, theme.as_markup(), self.style_enabled);
+                        markup_render.write(writer)?;
+                    }
+                    None => self.write_text(writer, &body)?,
+                },
+                _ => {
+                    self.write_text(writer, &body)?;
                 }
             }
         }
-        self.write_newline(writer)
+        self.write_newline(writer)?;
+        self.write_more_bytes(writer, more_bytes)?;
+        // The JWTs of the JSON strings (e.g. an OAuth token response) are decoded after the body
+        let theme = self.theme.response();
+        let now = date::now();
+        for (path, jwt) in jwts.iter() {
+            JwtRender::new(jwt, Some(path), now, theme.as_data(), self.style_enabled).write(writer)?;
+        }
+        Ok(())
+    }
+
+    // The body and the number of bytes not shown because of --max-body
+    fn shown_body(&self) -> (&[u8], usize) {
+        let bytes = self.body;
+        match self.workspace.max_body {
+            Some(max_body) if max_body < bytes.len() => {
+                // Not in the middle of a UTF-8 character
+                let shown = match std::str::from_utf8(&bytes[..max_body]) {
+                    Err(err) if err.error_len().is_none() => err.valid_up_to(),
+                    _ => max_body,
+                };
+                (&bytes[..shown], bytes.len() - shown)
+            }
+            _ => (bytes, 0),
+        }
+    }
+
+    // A single line (e.g. a minified script) is wrapped to the terminal width
+    fn write_text<W: Write>(&self, writer: &mut W, text: &str) -> Result<()> {
+        match self.workspace.output_width() {
+            Some(width) if !text.trim_end().contains('\n') => {
+                for (index, line) in wrap(text, width).into_iter().enumerate() {
+                    if index > 0 {
+                        self.write_newline(writer)?;
+                    }
+                    writer.write_all(line.as_bytes())?;
+                }
+                Ok(())
+            }
+            _ => writer.write_all(text.as_bytes()),
+        }
+    }
+
+    fn write_more_bytes<W: Write>(&self, writer: &mut W, more_bytes: usize) -> Result<()> {
+        if more_bytes > 0 {
+            writer.write_all(format!("… {} more bytes", more_bytes).as_bytes())?;
+            self.write_newline(writer)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn is_problem(&self) -> bool {
+        let content_type = self.response.headers().get(CONTENT_TYPE).and_then(|content_type| content_type.to_str().ok());
+        content_type.is_some_and(is_problem)
+    }
+
+    #[inline]
+    fn markup(&self) -> Option<Markup> {
+        let content_type = self.response.headers().get(CONTENT_TYPE)?;
+        Markup::from_content_type(content_type.to_str().ok()?)
+    }
+
+    #[inline]
+    fn write_timing<W: Write>(&self, writer: &mut W, timing: &Timing) -> Result<()> {
+        let theme = self.theme.response();
+        let timing_render = TimingRender::new(timing, theme.as_ref(), self.workspace.flags.timing_as_json, self.style_enabled);
+        timing_render.write(writer)
+    }
+
+    #[inline]
+    fn write_cookies<W: Write>(&self, writer: &mut W) -> Result<()> {
+        let response = self.response;
+        let theme = self.theme.response();
+        let https = response.url().scheme() == "https";
+        let cookie_render = CookieRender::new(response.headers(), https, date::now(), self.workspace.output_width(), theme.as_ref(), self.style_enabled);
+        cookie_render.write(writer)
     }
 
     #[inline]
@@ -112,12 +253,12 @@ impl<'a> ResponseRender<'a> {
 
     #[inline]
     fn write_headers<W: Write>(&self, writer: &mut W) -> Result<()> {
-        let response = self.response.borrow();
-        let headers = response.headers();
+        let headers = self.response.headers();
         let response_theme = self.theme.response();
         let header_theme = response_theme.as_header();
         let direction_theme = response_theme.as_direction();
-        let header_render = HeaderRender::new(self.workspace, headers, header_theme, direction_theme, DIRECTION_RESPONSE, self.style_enabled);
+        let data_theme = response_theme.as_data();
+        let header_render = HeaderRender::new(self.workspace, headers, header_theme, direction_theme, DIRECTION_RESPONSE, data_theme, self.style_enabled);
         header_render.write(writer)
     }
 }
@@ -0,0 +1,110 @@
+use super::{Render, TimingRender};
+use crate::request::timing::Timing;
+use crate::theme::ResponseTheme;
+use serde_json::json;
+use std::io::{Result, Write};
+use std::time::Duration;
+
+const LABEL_WIDTH: usize = 18;
+
+impl<'a> TimingRender<'a> {
+    pub fn new(timing: &'a Timing, theme: &'a dyn ResponseTheme, as_json: bool, style_enabled: bool) -> Self {
+        Self {
+            timing,
+            theme,
+            as_json,
+            style_enabled,
+        }
+    }
+}
+
+impl<'a> Render for TimingRender<'a> {
+    #[inline]
+    fn write<W>(&self, writer: &mut W) -> Result<()>
+    where
+        W: Write,
+    {
+        let timing = self.timing;
+        if self.as_json {
+            writer.write_all(to_json(timing).to_string().as_bytes())?;
+            return self.write_newline(writer);
+        }
+        self.write_line(writer, "DNS lookup", &optional_duration(timing.dns))?;
+        self.write_line(writer, "TCP connection", &optional_duration(timing.connect))?;
+        self.write_line(writer, "TLS handshake", &optional_duration(timing.tls))?;
+        self.write_line(writer, "First byte", &duration(timing.first_byte))?;
+        self.write_line(writer, "Content download", &duration(timing.download))?;
+        self.write_line(writer, "Total", &duration(timing.total))?;
+        self.write_line(writer, "Size", &format!("{} bytes", timing.size))
+    }
+
+    #[inline]
+    fn is_style_active(&self) -> bool {
+        self.style_enabled
+    }
+}
+
+impl<'a> TimingRender<'a> {
+    #[inline]
+    fn write_line<W: Write>(&self, writer: &mut W, label: &str, value: &str) -> Result<()> {
+        let label = format!("{:width$}", format!("{}:", label), width = LABEL_WIDTH);
+        self.write_with_style(writer, label.as_bytes(), &self.theme.timing_label())?;
+        self.write_with_style(writer, value.as_bytes(), &self.theme.timing_value())?;
+        self.write_newline(writer)
+    }
+}
+
+pub fn to_json(timing: &Timing) -> serde_json::Value {
+    json!({
+        "dns_ms": timing.dns.map(millis),
+        "connect_ms": timing.connect.map(millis),
+        "tls_ms": timing.tls.map(millis),
+        "first_byte_ms": millis(timing.first_byte),
+        "download_ms": millis(timing.download),
+        "total_ms": millis(timing.total),
+        "size_bytes": timing.size,
+    })
+}
+
+fn millis(duration: Duration) -> f64 {
+    (duration.as_secs_f64() * 1_000_000.0).round() / 1000.0
+}
+
+fn duration(duration: Duration) -> String {
+    format!("{:.1} ms", millis(duration))
+}
+
+fn optional_duration(value: Option<Duration>) -> String {
+    value.map(duration).unwrap_or_else(|| "-".into())
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{duration, to_json, Timing};
+    use std::time::Duration;
+
+    #[test]
+    fn format_duration() {
+        assert_eq!(duration(Duration::from_micros(12_345)), "12.3 ms");
+        assert_eq!(duration(Duration::from_secs(2)), "2000.0 ms");
+    }
+
+    #[test]
+    fn json() {
+        let timing = Timing {
+            dns: Some(Duration::from_micros(1_500)),
+            connect: None,
+            tls: None,
+            first_byte: Duration::from_millis(20),
+            download: Duration::from_millis(3),
+            total: Duration::from_micros(24_500),
+            size: 42,
+        };
+        assert_eq!(
+            to_json(&timing).to_string(),
+            r#"{"dns_ms":1.5,"connect_ms":null,"tls_ms":null,"first_byte_ms":20.0,"download_ms":3.0,"total_ms":24.5,"size_bytes":42}"#
+        );
+    }
+}
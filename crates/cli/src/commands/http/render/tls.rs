@@ -0,0 +1,91 @@
+use super::{Render, TlsRender};
+use crate::request::certificate::{CertificateInfo, Validity};
+use crate::request::tls::TlsInfo;
+use crate::theme::style::Style;
+use crate::theme::ResponseTheme;
+use std::io::{Result, Write};
+
+const LABEL_WIDTH: usize = 18;
+const EXPIRY_WARNING_DAYS: i64 = 30;
+
+impl<'a> TlsRender<'a> {
+    pub fn new(tls_info: &'a TlsInfo, theme: &'a dyn ResponseTheme, style_enabled: bool) -> Self {
+        Self { tls_info, theme, style_enabled }
+    }
+}
+
+impl<'a> Render for TlsRender<'a> {
+    #[inline]
+    fn write<W>(&self, writer: &mut W) -> Result<()>
+    where
+        W: Write,
+    {
+        let info = self.tls_info;
+        let value_style = self.theme.tls_value();
+        self.write_line(writer, "TLS version", info.version.as_deref().unwrap_or("-"), &value_style)?;
+        self.write_line(writer, "Cipher suite", info.cipher_suite.as_deref().unwrap_or("-"), &value_style)?;
+        self.write_line(writer, "ALPN", info.alpn.as_deref().unwrap_or("-"), &value_style)?;
+        for (index, cert) in info.certificates.iter().enumerate() {
+            self.write_certificate(writer, index, cert)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn is_style_active(&self) -> bool {
+        self.style_enabled
+    }
+}
+
+impl<'a> TlsRender<'a> {
+    #[inline]
+    fn write_certificate<W: Write>(&self, writer: &mut W, index: usize, cert: &CertificateInfo) -> Result<()> {
+        let value_style = self.theme.tls_value();
+        self.write_line(writer, &format!("Certificate #{}", index), &cert.subject, &value_style)?;
+        self.write_line(writer, "  Issuer", &cert.issuer, &value_style)?;
+        if !cert.alt_names.is_empty() {
+            self.write_line(writer, "  Alt names", &cert.alt_names.join(", "), &value_style)?;
+        }
+        self.write_line(writer, "  Not before", &cert.not_before, &value_style)?;
+        let (validity, is_warning) = describe_validity(&cert.validity);
+        let not_after = format!("{} ({})", cert.not_after, validity);
+        let style = if is_warning { self.theme.tls_warning() } else { value_style };
+        self.write_line(writer, "  Not after", &not_after, &style)?;
+        self.write_line(writer, "  SHA-256", &cert.fingerprint, &self.theme.tls_value())
+    }
+
+    #[inline]
+    fn write_line<W: Write>(&self, writer: &mut W, label: &str, value: &str, value_style: &Style) -> Result<()> {
+        let label = format!("{:width$}", format!("{}:", label), width = LABEL_WIDTH);
+        self.write_with_style(writer, label.as_bytes(), &self.theme.tls_label())?;
+        self.write_with_style(writer, value.as_bytes(), value_style)?;
+        self.write_newline(writer)
+    }
+}
+
+fn describe_validity(validity: &Validity) -> (String, bool) {
+    match validity {
+        Validity::NotYetValid => ("not yet valid".into(), true),
+        Validity::Expired { days_ago } => (format!("expired {} ago", days(*days_ago)), true),
+        Validity::Valid { days_left } => (format!("expires in {}", days(*days_left)), *days_left < EXPIRY_WARNING_DAYS),
+    }
+}
+
+fn days(count: i64) -> String {
+    format!("{} day{}", count, if count == 1 { "" } else { "s" })
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{describe_validity, Validity};
+
+    #[test]
+    fn validity() {
+        assert_eq!(describe_validity(&Validity::Valid { days_left: 90 }), ("expires in 90 days".into(), false));
+        assert_eq!(describe_validity(&Validity::Valid { days_left: 1 }), ("expires in 1 day".into(), true));
+        assert_eq!(describe_validity(&Validity::Expired { days_ago: 3 }), ("expired 3 days ago".into(), true));
+        assert_eq!(describe_validity(&Validity::NotYetValid), ("not yet valid".into(), true));
+    }
+}
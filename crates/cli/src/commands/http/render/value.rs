@@ -0,0 +1,50 @@
+use super::{Render, ValueRender};
+use crate::core::{OutputFormat, Workspace};
+use crate::shell::json::{self, JsonRender};
+use crate::shell::toml::TomlRender;
+use crate::shell::yaml::YamlRender;
+use crate::theme::ResponseTheme;
+use serde_json::Value;
+use std::borrow::Cow;
+use std::io::{Result, Write};
+
+impl<'a> ValueRender<'a> {
+    pub fn new(workspace: &'a Workspace, value: &'a Value, theme: &'a dyn ResponseTheme, style_enabled: bool) -> Self {
+        Self {
+            workspace,
+            value,
+            theme,
+            style_enabled,
+        }
+    }
+}
+
+impl<'a> Render for ValueRender<'a> {
+    #[inline]
+    fn write<W: Write>(&self, writer: &mut W) -> Result<()> {
+        let flags = self.workspace.flags;
+        match flags.output_format {
+            OutputFormat::Json => {
+                let compact = flags.show_response_compact || !flags.use_format;
+                JsonRender::new(self.value, compact, flags.sort_json, self.theme.as_data(), self.style_enabled).write(writer)
+            }
+            OutputFormat::Yaml => YamlRender::new(&self.sorted_value(), self.theme.as_data(), self.style_enabled).write(writer),
+            OutputFormat::Toml => TomlRender::new(&self.sorted_value(), self.theme.as_data(), self.style_enabled).write(writer),
+        }
+    }
+
+    #[inline]
+    fn is_style_active(&self) -> bool {
+        self.style_enabled
+    }
+}
+
+impl<'a> ValueRender<'a> {
+    fn sorted_value(&self) -> Cow<'_, Value> {
+        if self.workspace.flags.sort_json {
+            Cow::Owned(json::sort_keys(self.value.clone()))
+        } else {
+            Cow::Borrowed(self.value)
+        }
+    }
+}
@@ -1,7 +1,9 @@
 #[cfg(feature = "alias")]
 pub(crate) mod alias;
 pub(crate) mod args;
+pub(crate) mod curl;
 mod debug;
+pub(crate) mod har;
 pub(crate) mod http;
 
 use crate::{
@@ -1,5 +1,9 @@
 #[cfg(feature = "alias")]
 use crate::commands::alias::Error as AliasError;
+use crate::commands::curl::Error as FromCurlError;
+use crate::commands::har::Error as HarError;
+use crate::filter::Error as FilterError;
+use crate::theme::error::Error as ThemeError;
 
 #[cfg_attr(test, derive(Debug))]
 #[derive(PartialEq)]
@@ -17,6 +21,10 @@ pub enum Error {
     BadHeaderValue(String),
     Request(String),
     Io(String),
+    FromCurl(FromCurlError),
+    Har(HarError),
+    Filter(FilterError),
+    Theme(ThemeError),
     #[cfg(feature = "alias")]
     AliasCommand(AliasError),
     #[cfg(feature = "alias")]
@@ -1,3 +1,5 @@
+use super::{OutputFormat, PagerMode};
+
 #[cfg_attr(test, derive(Debug))]
 #[derive(Clone, Copy)]
 pub struct Flags {
@@ -5,10 +7,22 @@ pub struct Flags {
     pub show_help: bool,
     pub show_short_help: bool,
     pub debug: bool,
+    pub as_curl: bool,
 
     pub https: bool,
     pub http: bool,
     pub use_color: bool,
+    pub use_format: bool,
+    pub no_color: bool,
+    pub sort_headers: bool,
+    pub sort_json: bool,
+    pub output_format: OutputFormat,
+    pub as_table: bool,
+    pub as_hexdump: bool,
+    pub as_envelope: bool,
+    pub pager: PagerMode,
+    pub insecure: bool,
+    pub insecure_hostname: bool,
     pub show_direction: bool,
 
     pub as_json: bool,
@@ -23,4 +37,11 @@ pub struct Flags {
     pub show_response_headers: bool,
     pub show_response_compact: bool,
     pub show_response_body: bool,
+
+    pub show_timing: bool,
+    pub timing_as_json: bool,
+    pub show_tls_info: bool,
+    pub explain: bool,
+    pub decode_jwt: bool,
+    pub show_cookies: bool,
 }
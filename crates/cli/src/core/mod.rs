@@ -5,7 +5,7 @@ mod workspace;
 
 pub use error::Error;
 pub use flags::Flags;
-pub use types::{Args, HeaderMap, Mode, Result};
+pub use types::{Args, HeaderMap, Mode, OutputFormat, PagerMode, Result};
 pub use workspace::Workspace;
 
 pub trait PushDataItem {
@@ -10,4 +10,21 @@ pub enum Mode {
     Help,
     Version,
     Debug,
+    Curl,
+}
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    Json,
+    Yaml,
+    Toml,
+}
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(Clone, Copy, PartialEq)]
+pub enum PagerMode {
+    Never,
+    Always,
+    Auto,
 }
@@ -1,4 +1,6 @@
+use crate::filter::Filter;
 use crate::items::Items;
+use crate::request::charset::Charset;
 use crate::request::Method;
 use crate::theme::Theme;
 use std::cell::RefCell;
@@ -17,6 +19,13 @@ pub struct Workspace {
     pub items: RefCell<Items>,
     pub raw: Option<String>,
     pub certificate_authority_file: Option<String>,
+    pub filter: Option<Filter>,
+    pub table_columns: Vec<String>,
+    pub hexdump_limit: Option<usize>,
+    pub charset: Option<Charset>,
+    pub max_body: Option<usize>,
+    pub theme_name: Option<String>,
+    pub har_file: Option<String>,
 }
 
 impl Workspace {
@@ -27,10 +36,17 @@ impl Workspace {
             Mode::Version
         } else if self.flags.debug {
             Mode::Debug
+        } else if self.flags.as_curl {
+            Mode::Curl
         } else {
             Mode::Run
         }
     }
+
+    // The width of the terminal, none if the output is redirected
+    pub fn output_width(&self) -> Option<usize> {
+        Some(self.terminal_columns as usize).filter(|_| !self.output_redirected)
+    }
 }
 
 impl super::WorkspaceData for Workspace {
@@ -41,7 +57,7 @@ impl super::WorkspaceData for Workspace {
         self.flags.as_form
     }
     fn has_items(&self) -> bool {
-        self.items.borrow().len() > 0
+        !self.items.borrow().is_empty()
     }
 }
 
@@ -74,6 +90,13 @@ mod tests {
                 items: RefCell::new(Items::new()),
                 raw: None,
                 certificate_authority_file: None,
+                filter: None,
+                table_columns: Vec::new(),
+                hexdump_limit: None,
+                charset: None,
+                max_body: None,
+                theme_name: None,
+                har_file: None,
             };
             assert_eq!(args.is_json(), true);
             assert_eq!(args.has_items(), false);
@@ -99,6 +122,13 @@ mod tests {
                 items: RefCell::new(items),
                 raw: None,
                 certificate_authority_file: None,
+                filter: None,
+                table_columns: Vec::new(),
+                hexdump_limit: None,
+                charset: None,
+                max_body: None,
+                theme_name: None,
+                har_file: None,
             };
             assert_eq!(args.is_json(), true);
             assert_eq!(args.has_items(), true);
@@ -122,6 +152,13 @@ mod tests {
                 items: RefCell::new(Items::new()),
                 raw: None,
                 certificate_authority_file: None,
+                filter: None,
+                table_columns: Vec::new(),
+                hexdump_limit: None,
+                charset: None,
+                max_body: None,
+                theme_name: None,
+                har_file: None,
             };
             assert_eq!(args.is_json(), false);
             assert_eq!(args.has_items(), false);
@@ -145,6 +182,13 @@ mod tests {
                 items: RefCell::new(Items::new()),
                 raw: None,
                 certificate_authority_file: None,
+                filter: None,
+                table_columns: Vec::new(),
+                hexdump_limit: None,
+                charset: None,
+                max_body: None,
+                theme_name: None,
+                har_file: None,
             };
             assert_eq!(args.mode(), Mode::Version);
         }
@@ -165,6 +209,13 @@ mod tests {
                 items: RefCell::new(Items::new()),
                 raw: None,
                 certificate_authority_file: None,
+                filter: None,
+                table_columns: Vec::new(),
+                hexdump_limit: None,
+                charset: None,
+                max_body: None,
+                theme_name: None,
+                har_file: None,
             };
             assert_eq!(args.mode(), Mode::Help);
         }
@@ -0,0 +1,32 @@
+use crate::core::Error as CoreError;
+use std::fmt;
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(PartialEq)]
+pub enum Error {
+    MissingDot,
+    MissingKey(usize),
+    UnexpectedChar(char, usize),
+    UnterminatedString,
+    UnterminatedBracket,
+    InvalidIndex(String),
+}
+
+impl From<Error> for CoreError {
+    fn from(err: Error) -> CoreError {
+        CoreError::Filter(err)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::MissingDot => write!(f, "a filter starts with '.'"),
+            Error::MissingKey(position) => write!(f, "missing key at position {}", position),
+            Error::UnexpectedChar(c, position) => write!(f, "unexpected character '{}' at position {}", c, position),
+            Error::UnterminatedString => write!(f, "unterminated string"),
+            Error::UnterminatedBracket => write!(f, "missing ']'"),
+            Error::InvalidIndex(index) => write!(f, "invalid index '{}'", index),
+        }
+    }
+}
@@ -0,0 +1,239 @@
+mod error;
+
+pub(crate) use error::Error;
+use serde_json::Value;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+static NULL: Value = Value::Null;
+
+// A subset of the jq syntax: .key ."key" .[index] .[] and ..key (the key at any depth)
+#[cfg_attr(test, derive(Debug))]
+#[derive(PartialEq)]
+pub struct Filter {
+    steps: Vec<Step>,
+}
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(PartialEq)]
+enum Step {
+    Key(String),
+    Index(i64),
+    Iterate,
+    Recurse(Option<String>),
+}
+
+impl Filter {
+    pub fn parse(expression: &str) -> Result<Filter> {
+        let chars: Vec<char> = expression.trim().chars().collect();
+        if chars.first() != Some(&'.') {
+            return Err(Error::MissingDot);
+        }
+
+        let mut steps = Vec::new();
+        let mut pos = 0;
+        while pos < chars.len() {
+            match chars[pos] {
+                '.' if chars.get(pos + 1) == Some(&'.') => {
+                    pos += 2;
+                    let key = if pos < chars.len() && chars[pos] != '[' && chars[pos] != '.' {
+                        Some(key(&chars, &mut pos)?)
+                    } else {
+                        None
+                    };
+                    steps.push(Step::Recurse(key));
+                }
+                '.' => {
+                    pos += 1;
+                    if pos == chars.len() && pos > 1 {
+                        return Err(Error::MissingKey(pos + 1));
+                    }
+                    if pos < chars.len() && chars[pos] != '[' {
+                        steps.push(Step::Key(key(&chars, &mut pos)?));
+                    }
+                }
+                '[' => {
+                    pos += 1;
+                    steps.push(bracket(&chars, &mut pos)?);
+                }
+                // The errors are ignored like jq's optional operator
+                '?' => pos += 1,
+                c => return Err(Error::UnexpectedChar(c, pos + 1)),
+            }
+        }
+        Ok(Filter { steps })
+    }
+
+    pub fn apply<'a>(&self, value: &'a Value) -> Vec<&'a Value> {
+        let mut values = vec![value];
+        for step in self.steps.iter() {
+            values = values.into_iter().flat_map(|value| step.apply(value)).collect();
+        }
+        values
+    }
+}
+
+impl Step {
+    fn apply<'a>(&self, value: &'a Value) -> Vec<&'a Value> {
+        match (self, value) {
+            (Step::Key(key), Value::Object(map)) => vec![map.get(key).unwrap_or(&NULL)],
+            (Step::Index(index), Value::Array(values)) => {
+                let index = if *index < 0 { values.len() as i64 + index } else { *index };
+                vec![usize::try_from(index).ok().and_then(|index| values.get(index)).unwrap_or(&NULL)]
+            }
+            (Step::Key(_), Value::Null) | (Step::Index(_), Value::Null) => vec![&NULL],
+            (Step::Iterate, Value::Array(values)) => values.iter().collect(),
+            (Step::Iterate, Value::Object(map)) => map.values().collect(),
+            (Step::Recurse(key), value) => {
+                let mut values = Vec::new();
+                descendants(value, key.as_deref(), &mut values);
+                values
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+// The value itself and its descendants, or only the values of a given key at any depth
+fn descendants<'a>(value: &'a Value, key: Option<&str>, values: &mut Vec<&'a Value>) {
+    match key {
+        None => values.push(value),
+        Some(key) => {
+            if let Some(value) = value.as_object().and_then(|map| map.get(key)) {
+                values.push(value);
+            }
+        }
+    }
+    match value {
+        Value::Array(children) => children.iter().for_each(|child| descendants(child, key, values)),
+        Value::Object(map) => map.values().for_each(|child| descendants(child, key, values)),
+        _ => {}
+    }
+}
+
+fn key(chars: &[char], pos: &mut usize) -> Result<String> {
+    if chars.get(*pos) == Some(&'"') {
+        *pos += 1;
+        return string(chars, pos);
+    }
+    let start = *pos;
+    while *pos < chars.len() && (chars[*pos].is_alphanumeric() || chars[*pos] == '_' || chars[*pos] == '-' || chars[*pos] == '$') {
+        *pos += 1;
+    }
+    if *pos == start {
+        return match chars.get(*pos) {
+            Some(c) => Err(Error::UnexpectedChar(*c, *pos + 1)),
+            None => Err(Error::MissingKey(*pos + 1)),
+        };
+    }
+    Ok(chars[start..*pos].iter().collect())
+}
+
+fn string(chars: &[char], pos: &mut usize) -> Result<String> {
+    let mut value = String::new();
+    while let Some(c) = chars.get(*pos) {
+        *pos += 1;
+        match c {
+            '"' => return Ok(value),
+            '\\' => match chars.get(*pos) {
+                Some(escaped) => {
+                    value.push(*escaped);
+                    *pos += 1;
+                }
+                None => break,
+            },
+            c => value.push(*c),
+        }
+    }
+    Err(Error::UnterminatedString)
+}
+
+fn bracket(chars: &[char], pos: &mut usize) -> Result<Step> {
+    let step = match chars.get(*pos) {
+        Some(']') => Step::Iterate,
+        Some('"') => {
+            *pos += 1;
+            Step::Key(string(chars, pos)?)
+        }
+        Some(_) => {
+            let start = *pos;
+            while *pos < chars.len() && chars[*pos] != ']' {
+                *pos += 1;
+            }
+            let index: String = chars[start..*pos].iter().collect();
+            Step::Index(index.trim().parse().map_err(|_| Error::InvalidIndex(index.clone()))?)
+        }
+        None => return Err(Error::UnterminatedBracket),
+    };
+    if chars.get(*pos) != Some(&']') {
+        return Err(Error::UnterminatedBracket);
+    }
+    *pos += 1;
+    Ok(step)
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, Filter, Step};
+    use serde_json::{json, Value};
+
+    fn apply(expression: &str, value: &Value) -> Vec<Value> {
+        Filter::parse(expression).unwrap().apply(value).into_iter().cloned().collect()
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Filter::parse(".").unwrap().steps, vec![]);
+        assert_eq!(
+            Filter::parse(".items[].id").unwrap().steps,
+            vec![Step::Key("items".into()), Step::Iterate, Step::Key("id".into())]
+        );
+        assert_eq!(Filter::parse(".[0]").unwrap().steps, vec![Step::Index(0)]);
+        assert_eq!(Filter::parse(".a[-1]").unwrap().steps, vec![Step::Key("a".into()), Step::Index(-1)]);
+        assert_eq!(Filter::parse(r#"."a b"["c.d"]"#).unwrap().steps, vec![Step::Key("a b".into()), Step::Key("c.d".into())]);
+        assert_eq!(Filter::parse("..error").unwrap().steps, vec![Step::Recurse(Some("error".into()))]);
+        assert_eq!(Filter::parse("..").unwrap().steps, vec![Step::Recurse(None)]);
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Filter::parse("items"), Err(Error::MissingDot));
+        assert_eq!(Filter::parse(".a b"), Err(Error::UnexpectedChar(' ', 3)));
+        assert_eq!(Filter::parse(".a["), Err(Error::UnterminatedBracket));
+        assert_eq!(Filter::parse(".a[1"), Err(Error::UnterminatedBracket));
+        assert_eq!(Filter::parse(".a[x]"), Err(Error::InvalidIndex("x".into())));
+        assert_eq!(Filter::parse(r#"."a"#), Err(Error::UnterminatedString));
+        assert_eq!(Filter::parse(".a."), Err(Error::MissingKey(4)));
+    }
+
+    #[test]
+    fn keys() {
+        let value = json!({"data": {"user": {"name": "Alice"}}});
+        assert_eq!(apply(".", &value), vec![value.clone()]);
+        assert_eq!(apply(".data.user.name", &value), vec![json!("Alice")]);
+        assert_eq!(apply(".data.missing", &value), vec![Value::Null]);
+        assert_eq!(apply(".data.missing.name", &value), vec![Value::Null]);
+        assert_eq!(apply(".data.user.name.first", &value), Vec::<Value>::new());
+    }
+
+    #[test]
+    fn arrays() {
+        let value = json!({"items": [{"id": 1}, {"id": 2}, {"id": 3}]});
+        assert_eq!(apply(".items[].id", &value), vec![json!(1), json!(2), json!(3)]);
+        assert_eq!(apply(".items[1]", &value), vec![json!({"id": 2})]);
+        assert_eq!(apply(".items[-1].id", &value), vec![json!(3)]);
+        assert_eq!(apply(".items[5]", &value), vec![Value::Null]);
+        assert_eq!(apply(".items[0][]", &value), vec![json!(1)]);
+    }
+
+    #[test]
+    fn recursive() {
+        let value = json!({"error": "a", "items": [{"error": "b"}, {"ok": {"error": "c"}}]});
+        assert_eq!(apply("..error", &value), vec![json!("a"), json!("b"), json!("c")]);
+        assert_eq!(apply("..ok", &value), vec![json!({"error": "c"})]);
+        assert_eq!(apply(".[]..error", &value), vec![json!("b"), json!("c")]);
+        assert_eq!(apply("..", &json!([1, [2]])).len(), 4);
+    }
+}
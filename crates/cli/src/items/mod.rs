@@ -3,10 +3,11 @@ mod ser;
 mod value;
 
 use crate::core::{Error, PushDataItem};
-use std::collections::HashMap;
+use indexmap::IndexMap;
 use value::Value;
 
-pub type Items = HashMap<String, Value>;
+// The items keep the order of the command line
+pub type Items = IndexMap<String, Value>;
 
 const FORCE_STRING: &str = "/";
 
@@ -87,4 +88,15 @@ mod tests {
         assert_item_eq!("d-e=123.456", "d-e", value_number!((123.456)));
         assert_item_eq!("f_g=-123.456", "f_g", value_number!((-123.456)));
     }
+
+    #[test]
+    fn keep_the_order_of_the_items() {
+        let mut items = Items::new();
+        for item in ["c=3", "a=1", "b=2", "a=4"] {
+            let _ = items.push(item);
+        }
+        let keys: Vec<&str> = items.keys().map(String::as_str).collect();
+        assert_eq!(keys, vec!["c", "a", "b"]);
+        assert_eq!(items.get("a"), Some(&value_number!(4)));
+    }
 }
@@ -1,6 +1,5 @@
 use std::cmp::Ordering;
 use std::fmt::{self, Debug, Display};
-use std::i64;
 
 use serde::{Serialize, Serializer};
 
@@ -9,7 +9,7 @@ pub enum Value {
     // Array(Vec<Value>),
 }
 
-impl<'a> From<&'a str> for Value {
+impl From<&str> for Value {
     fn from(value: &str) -> Self {
         match value {
             "true" | "y" => Value::Bool(true),
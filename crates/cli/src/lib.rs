@@ -1,6 +1,9 @@
+#![cfg_attr(test, allow(clippy::bool_assert_comparison))]
+
 pub mod app;
 mod commands;
 mod core;
+mod filter;
 mod items;
 mod macros;
 mod parser;
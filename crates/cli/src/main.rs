@@ -15,7 +15,7 @@ fn main() {
 
     let out = io::stdout();
     let err = io::stderr();
-    let os_dirs = DefaultOsDirs::default();
+    let os_dirs = DefaultOsDirs;
     let mut shell = Shell::new(&os_dirs, out, err);
 
     let exit_code = rh::run(&mut os_args, &mut shell);
@@ -2,10 +2,26 @@ use regex::Regex;
 
 pub const RAW_FLAG: &str = "--raw=";
 pub const CAFILE_FLAG: &str = "--cafile=";
+pub const RAW_HEADER_FLAG: &str = "--raw-header=";
+pub const FILTER_FLAG: &str = "--filter=";
+pub const TABLE_FLAG: &str = "--table=";
+pub const HEXDUMP_FLAG: &str = "--hexdump=";
+pub const CHARSET_FLAG: &str = "--charset=";
+pub const MAX_BODY_FLAG: &str = "--max-body=";
+pub const THEME_FLAG: &str = "--theme=";
+pub const HAR_FLAG: &str = "--har=";
 
 pub trait ArgDetection {
     fn is_raw_flag(&self) -> bool;
     fn is_cafile_flag(&self) -> bool;
+    fn is_raw_header_flag(&self) -> bool;
+    fn is_filter_flag(&self) -> bool;
+    fn is_table_flag(&self) -> bool;
+    fn is_hexdump_flag(&self) -> bool;
+    fn is_charset_flag(&self) -> bool;
+    fn is_max_body_flag(&self) -> bool;
+    fn is_theme_flag(&self) -> bool;
+    fn is_har_flag(&self) -> bool;
     fn is_flag(&self) -> bool;
     fn is_header(&self) -> bool;
     fn is_item(&self) -> bool;
@@ -22,6 +38,30 @@ impl ArgDetection for String {
     fn is_cafile_flag(&self) -> bool {
         self.starts_with(CAFILE_FLAG)
     }
+    fn is_raw_header_flag(&self) -> bool {
+        self.starts_with(RAW_HEADER_FLAG)
+    }
+    fn is_filter_flag(&self) -> bool {
+        self.starts_with(FILTER_FLAG)
+    }
+    fn is_table_flag(&self) -> bool {
+        self.starts_with(TABLE_FLAG)
+    }
+    fn is_hexdump_flag(&self) -> bool {
+        self.starts_with(HEXDUMP_FLAG)
+    }
+    fn is_charset_flag(&self) -> bool {
+        self.starts_with(CHARSET_FLAG)
+    }
+    fn is_max_body_flag(&self) -> bool {
+        self.starts_with(MAX_BODY_FLAG)
+    }
+    fn is_theme_flag(&self) -> bool {
+        self.starts_with(THEME_FLAG)
+    }
+    fn is_har_flag(&self) -> bool {
+        self.starts_with(HAR_FLAG)
+    }
     fn is_flag(&self) -> bool {
         self.starts_with('-')
     }
@@ -85,6 +125,83 @@ mod tests {
         assert!(!arg!("-cafile=data").is_cafile_flag());
     }
 
+    #[test]
+    fn raw_header_flag() {
+        assert!(arg!("--raw-header=").is_raw_header_flag());
+        assert!(arg!("--raw-header=Cookie:a=b").is_raw_header_flag());
+    }
+    #[test]
+    fn not_raw_header_flag() {
+        assert!(!arg!("--raw-header").is_raw_header_flag());
+        assert!(!arg!("-raw-header=Cookie:a=b").is_raw_header_flag());
+    }
+
+    #[test]
+    fn table_flag() {
+        assert!(arg!("--table=").is_table_flag());
+        assert!(arg!("--table=id,name").is_table_flag());
+    }
+    #[test]
+    fn not_table_flag() {
+        assert!(!arg!("--table").is_table_flag());
+        assert!(!arg!("-table=id").is_table_flag());
+    }
+
+    #[test]
+    fn hexdump_flag() {
+        assert!(arg!("--hexdump=").is_hexdump_flag());
+        assert!(arg!("--hexdump=64").is_hexdump_flag());
+    }
+    #[test]
+    fn not_hexdump_flag() {
+        assert!(!arg!("--hexdump").is_hexdump_flag());
+        assert!(!arg!("-hexdump=64").is_hexdump_flag());
+    }
+
+    #[test]
+    fn charset_flag() {
+        assert!(arg!("--charset=").is_charset_flag());
+        assert!(arg!("--charset=latin1").is_charset_flag());
+    }
+    #[test]
+    fn not_charset_flag() {
+        assert!(!arg!("--charset").is_charset_flag());
+        assert!(!arg!("-charset=latin1").is_charset_flag());
+    }
+
+    #[test]
+    fn max_body_flag() {
+        assert!(arg!("--max-body=").is_max_body_flag());
+        assert!(arg!("--max-body=1000").is_max_body_flag());
+    }
+    #[test]
+    fn not_max_body_flag() {
+        assert!(!arg!("--max-body").is_max_body_flag());
+        assert!(!arg!("-max-body=1000").is_max_body_flag());
+    }
+
+    #[test]
+    fn theme_flag() {
+        assert!(arg!("--theme=").is_theme_flag());
+        assert!(arg!("--theme=light").is_theme_flag());
+    }
+    #[test]
+    fn not_theme_flag() {
+        assert!(!arg!("--theme").is_theme_flag());
+        assert!(!arg!("-theme=light").is_theme_flag());
+    }
+
+    #[test]
+    fn har_flag() {
+        assert!(arg!("--har=").is_har_flag());
+        assert!(arg!("--har=trace.har").is_har_flag());
+    }
+    #[test]
+    fn not_har_flag() {
+        assert!(!arg!("--har").is_har_flag());
+        assert!(!arg!("-har=trace.har").is_har_flag());
+    }
+
     #[test]
     fn flag() {
         assert!(arg!("-").is_flag());
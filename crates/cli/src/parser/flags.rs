@@ -1,5 +1,5 @@
 use super::Error;
-use crate::core::Flags;
+use crate::core::{Flags, OutputFormat, PagerMode};
 use regex::Regex;
 
 impl Default for Flags {
@@ -9,10 +9,22 @@ impl Default for Flags {
             show_help: false,
             show_short_help: false,
             debug: false,
+            as_curl: false,
 
             https: false,
             http: false,
             use_color: true,
+            use_format: true,
+            no_color: false,
+            sort_headers: false,
+            sort_json: false,
+            output_format: OutputFormat::Json,
+            as_table: false,
+            as_hexdump: false,
+            as_envelope: false,
+            pager: PagerMode::Auto,
+            insecure: false,
+            insecure_hostname: false,
             show_direction: false,
 
             as_json: false,
@@ -27,14 +39,23 @@ impl Default for Flags {
             show_response_headers: false,
             show_response_compact: false,
             show_response_body: true,
+
+            show_timing: false,
+            timing_as_json: false,
+            show_tls_info: false,
+            explain: false,
+            decode_jwt: false,
+            show_cookies: false,
         }
     }
 }
 
 impl Flags {
-    pub fn new(output_redirected: bool) -> Flags {
+    // The colours don't only depend on the output (see shell::color), the flags override them
+    pub fn new(output_redirected: bool, use_color: bool) -> Flags {
         Flags {
-            use_color: !output_redirected,
+            use_color,
+            use_format: !output_redirected,
             ..Default::default()
         }
     }
@@ -44,11 +65,33 @@ impl Flags {
             "--version" => self.show_version = true,
             "--help" => self.show_help = true,
             "--debug" => self.debug = true,
+            "--as-curl" => self.as_curl = true,
             "-U" | "--url" => self.show_request_url = true,
             "-s" | "--status" => self.show_response_status = true,
             "-d" | "--direction" => self.show_direction = true,
-            "-v" | "--verbose" => self.enable_verbose(),
-            "--pretty=c" | "--pretty=color" => self.use_color = true,
+            "-v" | "--verbose" => self.set_verbose(true),
+            "--pretty=c" | "--pretty=color" | "--pretty=all" => self.set_pretty(true, true),
+            "--pretty=colors" | "--pretty=colors-only" => self.set_pretty(true, false),
+            "--pretty=format" => self.set_pretty(false, true),
+            "--pretty=none" => self.set_pretty(false, false),
+            "--sort" => {
+                self.sort_headers = true;
+                self.sort_json = true;
+            }
+            "--sort=h" => self.sort_headers = true,
+            "--sort=j" => self.sort_json = true,
+            "--output-format=json" => self.output_format = OutputFormat::Json,
+            "--output-format=yaml" => self.output_format = OutputFormat::Yaml,
+            "--output-format=toml" => self.output_format = OutputFormat::Toml,
+            "--table" => self.as_table = true,
+            "--hexdump" => self.as_hexdump = true,
+            "--output=json" => self.as_envelope = true,
+            "--output=text" => self.as_envelope = false,
+            "--pager=never" => self.pager = PagerMode::Never,
+            "--pager=always" => self.pager = PagerMode::Always,
+            "--pager=auto" => self.pager = PagerMode::Auto,
+            "-k" | "--insecure" => self.insecure = true,
+            "--insecure-hostname" => self.insecure_hostname = true,
             "--json" => self.as_json = true,
             "--form" => self.as_form = true,
             "--http" => {
@@ -75,11 +118,24 @@ impl Flags {
             "--header" => self.show_response_headers = true,
             "-B" | "--req-body" => self.show_request_body = true,
             "-b" | "--body" => self.show_response_body = true,
+            "--body=n" => self.show_response_body = false,
             "-C" | "--req-compact" => self.show_request_compact = true,
             "-c" | "--compact" => self.show_response_compact = true,
+            "--timing" => self.show_timing = true,
+            "--tls-info" => self.show_tls_info = true,
+            "--explain" => self.explain = true,
+            "--decode-jwt" => self.decode_jwt = true,
+            "--cookies" => self.show_cookies = true,
+            "--timing=json" => {
+                self.show_timing = true;
+                self.timing_as_json = true;
+            }
             _ => {
-                let has_valid_compact_flags = self.extract_compact_flags(flag);
-                if !has_valid_compact_flags {
+                let is_valid = match flag.strip_prefix("--no-") {
+                    Some(name) => self.turn_off(name),
+                    None => self.extract_compact_flags(flag),
+                };
+                if !is_valid {
                     return Err(Error::InvalidFlag(flag.to_string()));
                 }
             }
@@ -87,52 +143,114 @@ impl Flags {
         Ok(())
     }
 
+    // --no-<flag> undoes a flag, for example one of an alias (the last flag wins)
+    fn turn_off(&mut self, name: &str) -> bool {
+        match name {
+            "debug" => self.debug = false,
+            "as-curl" => self.as_curl = false,
+            "url" => self.show_request_url = false,
+            "status" => self.show_response_status = false,
+            "direction" => self.show_direction = false,
+            "verbose" => self.set_verbose(false),
+            "color" => self.set_pretty(false, self.use_format),
+            "sort" => {
+                self.sort_headers = false;
+                self.sort_json = false;
+            }
+            "table" => self.as_table = false,
+            "hexdump" => self.as_hexdump = false,
+            "output" => self.as_envelope = false,
+            "pager" => self.pager = PagerMode::Never,
+            "insecure" => self.insecure = false,
+            "insecure-hostname" => self.insecure_hostname = false,
+            "json" => self.as_json = false,
+            "form" => self.as_form = false,
+            "http" => self.http = false,
+            "https" | "ssl" => self.https = false,
+            "headers" => {
+                self.show_request_headers = false;
+                self.show_response_headers = false;
+            }
+            "req-headers" => self.show_request_headers = false,
+            "header" => self.show_response_headers = false,
+            "req-body" => self.show_request_body = false,
+            "body" => self.show_response_body = false,
+            "req-compact" => self.show_request_compact = false,
+            "compact" => self.show_response_compact = false,
+            "timing" => {
+                self.show_timing = false;
+                self.timing_as_json = false;
+            }
+            "tls-info" => self.show_tls_info = false,
+            "explain" => self.explain = false,
+            "decode-jwt" => self.decode_jwt = false,
+            "cookies" => self.show_cookies = false,
+            _ => return false,
+        }
+        true
+    }
+
+    // -no-bBH... turns off the short flags
     fn extract_compact_flags(&mut self, flag: &str) -> bool {
-        // FIXME Need something like "-no-bBH..." to set the related flags to false
-        let valid = Regex::new(r"^\-[vcCdUshHbB]*$").unwrap().is_match(flag);
+        let (flag, on) = match flag.strip_prefix("-no") {
+            Some(flag) if flag.len() > 1 => (flag, false),
+            _ => (flag, true),
+        };
+        let valid = Regex::new(r"^\-[vcCdUshHbBk]*$").unwrap().is_match(flag);
         if valid {
             if flag.contains('v') {
-                self.enable_verbose();
+                self.set_verbose(on);
             }
             if flag.contains('c') {
-                self.show_response_compact = true;
+                self.show_response_compact = on;
             }
             if flag.contains('C') {
-                self.show_request_compact = true;
+                self.show_request_compact = on;
             }
             if flag.contains('d') {
-                self.show_direction = true;
+                self.show_direction = on;
             }
             if flag.contains('U') {
-                self.show_request_url = true;
+                self.show_request_url = on;
             }
             if flag.contains('s') {
-                self.show_response_status = true;
+                self.show_response_status = on;
             }
             if flag.contains('H') {
-                self.show_request_headers = true;
+                self.show_request_headers = on;
             }
             if flag.contains('h') {
-                self.show_response_headers = true;
+                self.show_response_headers = on;
             }
             if flag.contains('b') {
-                self.show_response_body = true;
+                self.show_response_body = on;
             }
             if flag.contains('B') {
-                self.show_request_body = true;
+                self.show_request_body = on;
+            }
+            if flag.contains('k') {
+                self.insecure = on;
             }
         }
         valid
     }
 
-    fn enable_verbose(&mut self) {
-        self.show_direction = true;
-        self.show_request_url = true;
-        self.show_response_status = true;
-        self.show_request_headers = true;
-        self.show_response_headers = true;
-        self.show_request_body = true;
-        self.show_response_body = true;
+    // The response body is shown by default, so it isn't hidden when verbose is turned off
+    fn set_verbose(&mut self, on: bool) {
+        self.show_direction = on;
+        self.show_request_url = on;
+        self.show_response_status = on;
+        self.show_request_headers = on;
+        self.show_response_headers = on;
+        self.show_request_body = on;
+        self.show_response_body |= on;
+        self.show_tls_info = on;
+    }
+
+    fn set_pretty(&mut self, color: bool, format: bool) {
+        self.use_color = color;
+        self.no_color = !color;
+        self.use_format = format;
     }
 
     fn is_contradictory_scheme(&self) -> bool {
@@ -144,15 +262,15 @@ impl Flags {
 
 #[cfg(test)]
 mod tests {
-    use super::{Error, Flags};
+    use super::{Error, Flags, OutputFormat, PagerMode};
 
     macro_rules! flag {
         () => {{
-            Flags::new(false)
+            Flags::new(false, true)
         }};
         ( $( $elem:expr ),* ) => {
             {
-                let mut temp_flags = Flags::new(false);
+                let mut temp_flags = Flags::new(false, true);
                 $(
                     let _ = temp_flags.push($elem);
                 )*
@@ -191,6 +309,158 @@ mod tests {
         assert_eq!(res.unwrap_err(), Error::ContradictoryScheme);
     }
 
+    #[test]
+    fn timing() {
+        let flags = flag!["--timing"];
+        assert_eq!(flags.show_timing, true);
+        assert_eq!(flags.timing_as_json, false);
+
+        let flags = flag!["--timing=json"];
+        assert_eq!(flags.show_timing, true);
+        assert_eq!(flags.timing_as_json, true);
+    }
+
+    #[test]
+    fn tls_info() {
+        let flags = flag!["--tls-info"];
+        assert_eq!(flags.show_tls_info, true);
+
+        let flags = flag!["-v"];
+        assert_eq!(flags.show_tls_info, true);
+
+        let flags = flag!["-UhH"];
+        assert_eq!(flags.show_tls_info, false);
+    }
+
+    #[test]
+    fn insecure() {
+        let flags = flag!["-k"];
+        assert_eq!(flags.insecure, true);
+        assert_eq!(flags.insecure_hostname, false);
+
+        let flags = flag!["-kUh"];
+        assert_eq!(flags.insecure, true);
+        assert_eq!(flags.show_request_url, true);
+
+        let flags = flag!["--insecure-hostname"];
+        assert_eq!(flags.insecure, false);
+        assert_eq!(flags.insecure_hostname, true);
+    }
+
+    #[test]
+    fn pretty() {
+        let flags = flag![];
+        assert_eq!((flags.use_color, flags.use_format), (true, true));
+
+        let flags = Flags::new(true, false);
+        assert_eq!((flags.use_color, flags.use_format), (false, false));
+
+        let flags = flag!["--pretty=none"];
+        assert_eq!((flags.use_color, flags.use_format), (false, false));
+
+        let flags = flag!["--pretty=colors"];
+        assert_eq!((flags.use_color, flags.use_format), (true, false));
+
+        let flags = flag!["--pretty=colors-only"];
+        assert_eq!((flags.use_color, flags.use_format), (true, false));
+
+        let flags = flag!["--pretty=c"];
+        assert_eq!((flags.use_color, flags.use_format), (true, true));
+
+        let flags = flag!["--pretty=format"];
+        assert_eq!((flags.use_color, flags.use_format), (false, true));
+
+        let mut flags = Flags::new(true, false);
+        let _ = flags.push("--pretty=all");
+        assert_eq!((flags.use_color, flags.use_format), (true, true));
+
+        let flags = flag!["--pretty=none", "--pretty=format"];
+        assert_eq!((flags.use_color, flags.use_format), (false, true));
+    }
+
+    #[test]
+    fn no_color() {
+        let flags = flag![];
+        assert_eq!((flags.use_color, flags.no_color), (true, false));
+
+        let flags = flag!["--no-color"];
+        assert_eq!((flags.use_color, flags.use_format, flags.no_color), (false, true, true));
+
+        let flags = flag!["--pretty=format"];
+        assert_eq!(flags.no_color, true);
+
+        let mut flags = Flags::new(true, false);
+        assert_eq!(flags.no_color, false);
+        let _ = flags.push("--no-color");
+        assert_eq!((flags.use_color, flags.use_format, flags.no_color), (false, false, true));
+
+        let flags = flag!["--no-color", "--pretty=colors-only"];
+        assert_eq!((flags.use_color, flags.no_color), (true, false));
+
+        let flags = Flags::new(false, false);
+        assert_eq!((flags.use_color, flags.use_format, flags.no_color), (false, true, false));
+    }
+
+    #[test]
+    fn sort() {
+        let flags = flag![];
+        assert_eq!((flags.sort_headers, flags.sort_json), (false, false));
+
+        let flags = flag!["--sort"];
+        assert_eq!((flags.sort_headers, flags.sort_json), (true, true));
+
+        let flags = flag!["--sort=h"];
+        assert_eq!((flags.sort_headers, flags.sort_json), (true, false));
+
+        let flags = flag!["--sort=j"];
+        assert_eq!((flags.sort_headers, flags.sort_json), (false, true));
+    }
+
+    #[test]
+    fn output_format() {
+        let flags = flag![];
+        assert_eq!(flags.output_format, OutputFormat::Json);
+
+        let flags = flag!["--output-format=yaml"];
+        assert_eq!(flags.output_format, OutputFormat::Yaml);
+
+        let flags = flag!["--output-format=toml"];
+        assert_eq!(flags.output_format, OutputFormat::Toml);
+
+        let mut flags = flag![];
+        assert_eq!(flags.push("--output-format=xml"), Err(Error::InvalidFlag("--output-format=xml".into())));
+    }
+
+    #[test]
+    fn envelope() {
+        let flags = flag![];
+        assert_eq!(flags.as_envelope, false);
+
+        let flags = flag!["--output=json"];
+        assert_eq!(flags.as_envelope, true);
+
+        let flags = flag!["--output=json", "--output=text"];
+        assert_eq!(flags.as_envelope, false);
+
+        let flags = flag!["--output=json", "--no-output"];
+        assert_eq!(flags.as_envelope, false);
+    }
+
+    #[test]
+    fn pager() {
+        let flags = flag![];
+        assert_eq!(flags.pager, PagerMode::Auto);
+
+        let flags = flag!["--pager=never"];
+        assert_eq!(flags.pager, PagerMode::Never);
+
+        let flags = flag!["--pager=never", "--pager=always"];
+        assert_eq!(flags.pager, PagerMode::Always);
+
+        let mut flags = flag![];
+        assert_eq!(flags.push("--pager"), Err(Error::InvalidFlag("--pager".into())));
+    }
+
     #[test]
     fn compact_flags() {
         let flags = flag!["-hH"];
@@ -213,4 +483,81 @@ mod tests {
         assert!(res.is_err());
         assert_eq!(res.unwrap_err(), Error::InvalidFlag(flag.into()));
     }
+
+    #[test]
+    fn negation() {
+        let default = format!("{:?}", flag![]);
+        for name in [
+            "debug",
+            "as-curl",
+            "url",
+            "status",
+            "direction",
+            "verbose",
+            "sort",
+            "table",
+            "hexdump",
+            "insecure",
+            "insecure-hostname",
+            "json",
+            "form",
+            "http",
+            "https",
+            "ssl",
+            "headers",
+            "req-headers",
+            "header",
+            "req-body",
+            "req-compact",
+            "compact",
+            "timing",
+            "tls-info",
+            "explain",
+            "decode-jwt",
+            "cookies",
+        ] {
+            let flags = flag![&format!("--{}", name), &format!("--no-{}", name)];
+            assert_eq!(format!("{:?}", flags), default, "--no-{}", name);
+        }
+
+        let flags = flag!["--no-body"];
+        assert_eq!(flags.show_response_body, false);
+        let flags = flag!["--body=n"];
+        assert_eq!(flags.show_response_body, false);
+        let flags = flag!["--no-body", "--body"];
+        assert_eq!(flags.show_response_body, true);
+
+        let flags = flag!["--timing=json", "--no-timing"];
+        assert_eq!((flags.show_timing, flags.timing_as_json), (false, false));
+
+        let flags = flag!["--no-pager"];
+        assert_eq!(flags.pager, PagerMode::Never);
+
+        let flags = flag!["-v", "--no-verbose", "-U"];
+        assert_eq!((flags.show_request_url, flags.show_request_headers, flags.show_response_body), (true, false, true));
+
+        let flags = flag!["-k", "--no-insecure", "-k"];
+        assert_eq!(flags.insecure, true);
+
+        let mut flags = flag![];
+        assert_eq!(flags.push("--no-version"), Err(Error::InvalidFlag("--no-version".into())));
+        assert_eq!(flags.push("--no-"), Err(Error::InvalidFlag("--no-".into())));
+    }
+
+    #[test]
+    fn negated_compact_flags() {
+        let flags = flag!["-UhHb", "-no-hb"];
+        assert_eq!((flags.show_request_url, flags.show_request_headers), (true, true));
+        assert_eq!((flags.show_response_headers, flags.show_response_body), (false, false));
+
+        let flags = flag!["-v", "-no-v"];
+        assert_eq!(format!("{:?}", flags), format!("{:?}", flag![]));
+
+        let flags = flag!["-no-k", "-k"];
+        assert_eq!(flags.insecure, true);
+
+        let mut flags = flag![];
+        assert_eq!(flags.push("-no-"), Err(Error::InvalidFlag("-no-".into())));
+        assert_eq!(flags.push("-no-ha"), Err(Error::InvalidFlag("-no-ha".into())));
+    }
 }
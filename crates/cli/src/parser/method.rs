@@ -5,11 +5,7 @@ pub fn from_str(keyword: &str) -> Option<Method> {
         return None;
     }
 
-    let method = reqwest::Method::from_bytes(keyword.as_bytes());
-    match method {
-        Ok(m) => Some(m),
-        _ => None,
-    }
+    reqwest::Method::from_bytes(keyword.as_bytes()).ok()
 }
 
 fn is_valid(keyword: &str) -> bool {
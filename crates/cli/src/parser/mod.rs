@@ -1,4 +1,4 @@
-mod core;
+pub(crate) mod core;
 mod error;
 mod flags;
 mod headers;
@@ -15,6 +15,23 @@ use std::cell::RefCell;
 use std::io::{self, Read};
 
 pub fn execute(args: &[String]) -> Result<Workspace> {
+    parse(args, !stream::is_stdin())
+}
+
+pub fn execute_without_stdin(args: &[String]) -> Result<Workspace> {
+    parse(args, false)
+}
+
+// Known before parsing, so that the errors of the parsing aren't coloured either
+pub fn no_color(args: &[String]) -> bool {
+    let mut flags = Flags::default();
+    for arg in args.iter().filter(|arg| arg.starts_with("--")) {
+        let _ = flags.push(arg);
+    }
+    flags.no_color
+}
+
+fn parse(args: &[String], input_redirected: bool) -> Result<Workspace> {
     validate_there_are_enough_args(args)?;
 
     let output_redirected = !stream::is_stdout();
@@ -26,8 +43,14 @@ pub fn execute(args: &[String]) -> Result<Workspace> {
     let urls = normalizer.urls;
     let mut raw = normalizer.raw.take();
     let certificate_authority_file = normalizer.certificate_authority_file.take();
+    let filter = normalizer.filter.take();
+    let table_columns = std::mem::take(&mut normalizer.table_columns);
+    let hexdump_limit = normalizer.hexdump_limit;
+    let charset = normalizer.charset;
+    let max_body = normalizer.max_body;
+    let theme_name = normalizer.theme_name.take();
+    let har_file = normalizer.har_file.take();
 
-    let input_redirected = !stream::is_stdin();
     if !is_flag_only_command(&flags) {
         validate_processed_urls(&urls, &flags, args)?;
         validate_there_is_no_mix_of_items_and_raw_and_stdin(&items, &raw, input_redirected)?;
@@ -48,6 +71,13 @@ pub fn execute(args: &[String]) -> Result<Workspace> {
         items: RefCell::new(items),
         raw,
         certificate_authority_file,
+        filter,
+        table_columns,
+        hexdump_limit,
+        charset,
+        max_body,
+        theme_name,
+        har_file,
     })
 }
 
@@ -115,6 +145,7 @@ mod tests {
 
     mod basic {
         use super::*;
+        use crate::core::Mode;
 
         #[test]
         fn show_version() {
@@ -143,6 +174,14 @@ mod tests {
             let parser = execute(&args).unwrap();
             assert_eq!(parser.flags.show_short_help, true);
         }
+
+        #[test]
+        fn as_curl() {
+            let args = rh_test::args!["localhost", "--as-curl"];
+            let parser = execute(&args).unwrap();
+            assert_eq!(parser.flags.as_curl, true);
+            assert_eq!(parser.mode(), Mode::Curl);
+        }
     }
 
     mod validate {
@@ -154,12 +193,16 @@ mod tests {
 
         #[test]
         fn flag_only_commands() {
-            let mut flags = Flags::default();
-            flags.show_help = true;
+            let flags = Flags {
+                show_help: true,
+                ..Flags::default()
+            };
             assert!(is_flag_only_command(&flags));
 
-            let mut flags = Flags::default();
-            flags.show_version = true;
+            let flags = Flags {
+                show_version: true,
+                ..Flags::default()
+            };
             assert!(is_flag_only_command(&flags));
         }
 
@@ -336,4 +379,16 @@ mod tests {
             assert_eq!(parser.unwrap_err(), Error::ItemsAndRawMix);
         }
     }
+
+    mod no_color {
+        use super::*;
+
+        #[test]
+        fn no_color_even_if_invalid() {
+            assert!(no_color(&rh_test::args!["--no-color", "--bogus"]));
+            assert!(no_color(&rh_test::args!["test.com", "--pretty=none"]));
+            assert!(!no_color(&rh_test::args!["test.com", "--no-color", "--pretty=colors"]));
+            assert!(!no_color(&rh_test::args!["test.com", "name=--no-color"]));
+        }
+    }
 }
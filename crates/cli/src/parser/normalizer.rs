@@ -1,11 +1,14 @@
-use super::core::{ArgDetection, CAFILE_FLAG, RAW_FLAG};
+use super::core::{ArgDetection, CAFILE_FLAG, CHARSET_FLAG, FILTER_FLAG, HAR_FLAG, HEXDUMP_FLAG, MAX_BODY_FLAG, RAW_FLAG, RAW_HEADER_FLAG, TABLE_FLAG, THEME_FLAG};
 use super::headers::HeaderMap;
 use super::method;
 use super::url;
 use crate::core::Flags;
 use crate::core::{Error, PushDataItem};
+use crate::filter::Filter;
 use crate::items::Items;
+use crate::request::charset::{self, Charset};
 use crate::request::Method;
+use crate::shell::color;
 
 #[cfg_attr(test, derive(Debug))]
 pub struct Normalizer {
@@ -16,17 +19,31 @@ pub struct Normalizer {
     pub items: Items,
     pub raw: Option<String>,
     pub certificate_authority_file: Option<String>,
+    pub filter: Option<Filter>,
+    pub table_columns: Vec<String>,
+    pub hexdump_limit: Option<usize>,
+    pub charset: Option<Charset>,
+    pub max_body: Option<usize>,
+    pub theme_name: Option<String>,
+    pub har_file: Option<String>,
 }
 
 impl Normalizer {
     pub fn parse(args: &[String], output_redirected: bool, default_scheme: &str, default_host: &str) -> Result<Normalizer, Error> {
         let mut method: Option<Method> = None;
         let mut urls: Vec<String> = Vec::new();
-        let mut flags = Flags::new(output_redirected);
+        let mut flags = Flags::new(output_redirected, color::enabled(!output_redirected));
         let mut headers = HeaderMap::new();
         let mut items = Items::new();
         let mut raw: Option<String> = None;
         let mut certificate_authority_file: Option<String> = None;
+        let mut filter: Option<Filter> = None;
+        let mut table_columns: Vec<String> = Vec::new();
+        let mut hexdump_limit: Option<usize> = None;
+        let mut charset: Option<Charset> = None;
+        let mut max_body: Option<usize> = None;
+        let mut theme_name: Option<String> = None;
+        let mut har_file: Option<String> = None;
         let args_length = args.len();
 
         for (arg_index, arg) in args.iter().enumerate().take(args_length) {
@@ -60,6 +77,40 @@ impl Normalizer {
                 if !cafile.is_empty() {
                     certificate_authority_file = Some(cafile);
                 }
+            } else if arg.is_filter_flag() {
+                let expression = &arg[FILTER_FLAG.len()..];
+                if !expression.is_empty() {
+                    filter = Some(Filter::parse(expression)?);
+                }
+            } else if arg.is_table_flag() {
+                flags.as_table = true;
+                table_columns = arg[TABLE_FLAG.len()..]
+                    .split(',')
+                    .map(|column| column.trim().to_string())
+                    .filter(|column| !column.is_empty())
+                    .collect();
+            } else if arg.is_hexdump_flag() {
+                flags.as_hexdump = true;
+                let limit = &arg[HEXDUMP_FLAG.len()..];
+                if !limit.is_empty() {
+                    hexdump_limit = Some(limit.parse().map_err(|_| Error::InvalidFlag(arg.clone()))?);
+                }
+            } else if arg.is_charset_flag() {
+                charset = Some(charset::from_label(&arg[CHARSET_FLAG.len()..]).ok_or_else(|| Error::InvalidFlag(arg.clone()))?);
+            } else if arg.is_max_body_flag() {
+                max_body = Some(arg[MAX_BODY_FLAG.len()..].parse().map_err(|_| Error::InvalidFlag(arg.clone()))?);
+            } else if arg.is_theme_flag() {
+                let name = arg[THEME_FLAG.len()..].to_string();
+                if !name.is_empty() {
+                    theme_name = Some(name);
+                }
+            } else if arg.is_har_flag() {
+                let path = arg[HAR_FLAG.len()..].to_string();
+                if !path.is_empty() {
+                    har_file = Some(path);
+                }
+            } else if arg.is_raw_header_flag() {
+                headers.push(&arg[RAW_HEADER_FLAG.len()..])?;
             } else if arg.is_flag() {
                 flags.push(arg)?;
             } else if arg.is_header() {
@@ -100,6 +151,13 @@ impl Normalizer {
             items,
             raw,
             certificate_authority_file,
+            filter,
+            table_columns,
+            hexdump_limit,
+            charset,
+            max_body,
+            theme_name,
+            har_file,
         })
     }
 
@@ -268,6 +326,21 @@ mod tests {
         }
     }
 
+    mod raw_header {
+        use super::Normalizer;
+        use super::{DEFAULT_HOST, DEFAULT_SCHEME};
+
+        #[test]
+        fn header_with_equal_sign() {
+            let args: Vec<String> = rh_test::args!["test.com", "--raw-header=Cookie:a=b; c=d", "--raw-header=X-Count:5"];
+            let normalizer = Normalizer::parse(&args, false, DEFAULT_SCHEME, DEFAULT_HOST).unwrap();
+            assert_eq!(normalizer.urls.len(), 1);
+            assert!(normalizer.items.is_empty());
+            assert_eq!(normalizer.headers.get("cookie").unwrap(), "a=b; c=d");
+            assert_eq!(normalizer.headers.get("x-count").unwrap(), "5");
+        }
+    }
+
     mod raw {
         use super::Normalizer;
         use super::{DEFAULT_HOST, DEFAULT_SCHEME};
@@ -285,4 +358,82 @@ mod tests {
             assert_eq!(normalizer.flags.as_form, false);
         }
     }
+    mod table {
+        use super::Normalizer;
+        use super::{DEFAULT_HOST, DEFAULT_SCHEME};
+
+        #[test]
+        fn all_columns() {
+            let args: Vec<String> = rh_test::args!["test.com", "--table"];
+            let normalizer = Normalizer::parse(&args, false, DEFAULT_SCHEME, DEFAULT_HOST).unwrap();
+            assert!(normalizer.flags.as_table);
+            assert!(normalizer.table_columns.is_empty());
+        }
+
+        #[test]
+        fn some_columns() {
+            let args: Vec<String> = rh_test::args!["test.com", "--table=id, name,,status"];
+            let normalizer = Normalizer::parse(&args, false, DEFAULT_SCHEME, DEFAULT_HOST).unwrap();
+            assert!(normalizer.flags.as_table);
+            assert_eq!(normalizer.table_columns, vec!["id", "name", "status"]);
+        }
+    }
+
+    mod hexdump {
+        use super::{Error, Normalizer};
+        use super::{DEFAULT_HOST, DEFAULT_SCHEME};
+
+        #[test]
+        fn limit() {
+            let args: Vec<String> = rh_test::args!["test.com", "--hexdump=64"];
+            let normalizer = Normalizer::parse(&args, false, DEFAULT_SCHEME, DEFAULT_HOST).unwrap();
+            assert!(normalizer.flags.as_hexdump);
+            assert_eq!(normalizer.hexdump_limit, Some(64));
+        }
+
+        #[test]
+        fn invalid_limit() {
+            let args: Vec<String> = rh_test::args!["test.com", "--hexdump=abc"];
+            let normalizer = Normalizer::parse(&args, false, DEFAULT_SCHEME, DEFAULT_HOST);
+            assert_eq!(normalizer.err(), Some(Error::InvalidFlag("--hexdump=abc".into())));
+        }
+    }
+
+    mod charset {
+        use super::{Error, Normalizer};
+        use super::{DEFAULT_HOST, DEFAULT_SCHEME};
+
+        #[test]
+        fn known_charset() {
+            let args: Vec<String> = rh_test::args!["test.com", "--charset=ISO-8859-1"];
+            let normalizer = Normalizer::parse(&args, false, DEFAULT_SCHEME, DEFAULT_HOST).unwrap();
+            assert_eq!(normalizer.charset, Some(encoding_rs::WINDOWS_1252));
+        }
+
+        #[test]
+        fn unknown_charset() {
+            let args: Vec<String> = rh_test::args!["test.com", "--charset=klingon"];
+            let normalizer = Normalizer::parse(&args, false, DEFAULT_SCHEME, DEFAULT_HOST);
+            assert_eq!(normalizer.err(), Some(Error::InvalidFlag("--charset=klingon".into())));
+        }
+    }
+
+    mod max_body {
+        use super::{Error, Normalizer};
+        use super::{DEFAULT_HOST, DEFAULT_SCHEME};
+
+        #[test]
+        fn limit() {
+            let args: Vec<String> = rh_test::args!["test.com", "--max-body=1000"];
+            let normalizer = Normalizer::parse(&args, false, DEFAULT_SCHEME, DEFAULT_HOST).unwrap();
+            assert_eq!(normalizer.max_body, Some(1000));
+        }
+
+        #[test]
+        fn invalid_limit() {
+            let args: Vec<String> = rh_test::args!["test.com", "--max-body=-1"];
+            let normalizer = Normalizer::parse(&args, false, DEFAULT_SCHEME, DEFAULT_HOST);
+            assert_eq!(normalizer.err(), Some(Error::InvalidFlag("--max-body=-1".into())));
+        }
+    }
 }
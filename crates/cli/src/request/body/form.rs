@@ -3,5 +3,23 @@ use serde_urlencoded::ser::Error;
 use crate::items::Items;
 
 pub fn serialize(items: &Items) -> Result<String, Error> {
-    serde_urlencoded::to_string(&items)
+    serde_urlencoded::to_string(items)
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::serialize;
+    use crate::core::PushDataItem;
+    use crate::items::Items;
+
+    #[test]
+    fn encoded_values() {
+        let mut items = Items::new();
+        for item in ["q=a b&c", "ok=true", "count=3"] {
+            let _ = items.push(item);
+        }
+        assert_eq!(serialize(&items).unwrap(), "q=a+b%26c&ok=true&count=3");
+    }
 }
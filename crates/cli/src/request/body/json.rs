@@ -5,3 +5,21 @@ use crate::items::Items;
 pub fn serialize(items: &Items) -> Result<String, Error> {
     serde_json::to_string(&items)
 }
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::serialize;
+    use crate::core::PushDataItem;
+    use crate::items::Items;
+
+    #[test]
+    fn typed_values() {
+        let mut items = Items::new();
+        for item in ["name=rh", "count=3", "ok=true", "id/=42"] {
+            let _ = items.push(item);
+        }
+        assert_eq!(serialize(&items).unwrap(), r#"{"name":"rh","count":3,"ok":true,"id":"42"}"#);
+    }
+}
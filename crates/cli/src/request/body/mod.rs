@@ -17,7 +17,7 @@ impl Body for RequestBuilder {
     }
 }
 
-fn build_body(args: &Workspace) -> Option<String> {
+pub fn build_body(args: &Workspace) -> Option<String> {
     if args.has_items() {
         if args.is_json() {
             Some(json::serialize(&args.items.borrow()).unwrap())
@@ -1,21 +1,138 @@
 use reqwest::Certificate;
 
-use crate::core::Result;
+use crate::core::{Error, Result};
+use ring::digest;
 use std::io::Read;
+use std::net::IpAddr;
 use std::{fs::File, path::Path};
+use x509_parser::extensions::GeneralName;
 
 pub fn load<P: AsRef<Path>>(path: P) -> Result<Certificate> {
-    let mut buf = Vec::new();
-    File::open(&path)?.read_to_end(&mut buf)?;
+    let buf = read(&path)?;
     let cert = certificate(path, &buf)?;
     Ok(cert)
 }
 
-fn certificate<P: AsRef<Path>>(path: P, buf: &[u8]) -> Result<Certificate> {
-    let cert = if Some(std::ffi::OsStr::new("der")) == path.as_ref().extension() {
-        Certificate::from_der(buf)
+pub fn load_der<P: AsRef<Path>>(path: P) -> Result<Vec<Vec<u8>>> {
+    let buf = read(&path)?;
+    if is_der(&path) {
+        Ok(vec![buf])
     } else {
-        Certificate::from_pem(buf)
-    }?;
+        let certs = rustls_pemfile::certs(&mut buf.as_slice())?;
+        if certs.is_empty() {
+            return Err(Error::Request(format!("no certificate found in {}", path.as_ref().display())));
+        }
+        Ok(certs)
+    }
+}
+
+fn read<P: AsRef<Path>>(path: P) -> Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    File::open(&path)?.read_to_end(&mut buf)?;
+    Ok(buf)
+}
+
+fn certificate<P: AsRef<Path>>(path: P, buf: &[u8]) -> Result<Certificate> {
+    let cert = if is_der(path) { Certificate::from_der(buf) } else { Certificate::from_pem(buf) }?;
     Ok(cert)
 }
+
+fn is_der<P: AsRef<Path>>(path: P) -> bool {
+    Some(std::ffi::OsStr::new("der")) == path.as_ref().extension()
+}
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(PartialEq)]
+pub struct CertificateInfo {
+    pub subject: String,
+    pub issuer: String,
+    pub alt_names: Vec<String>,
+    pub not_before: String,
+    pub not_after: String,
+    pub validity: Validity,
+    pub fingerprint: String,
+}
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(PartialEq)]
+pub enum Validity {
+    NotYetValid,
+    Valid { days_left: i64 },
+    Expired { days_ago: i64 },
+}
+
+pub fn describe(der: &[u8], now: i64) -> Option<CertificateInfo> {
+    let (_, cert) = x509_parser::parse_x509_certificate(der).ok()?;
+    let validity = cert.validity();
+    let alt_names = match cert.subject_alternative_name() {
+        Ok(Some(san)) => san.value.general_names.iter().filter_map(alt_name).collect(),
+        _ => Vec::new(),
+    };
+    Some(CertificateInfo {
+        subject: cert.subject().to_string(),
+        issuer: cert.issuer().to_string(),
+        alt_names,
+        not_before: validity.not_before.to_string(),
+        not_after: validity.not_after.to_string(),
+        validity: self::validity(validity.not_before.timestamp(), validity.not_after.timestamp(), now),
+        fingerprint: fingerprint(der),
+    })
+}
+
+fn alt_name(name: &GeneralName) -> Option<String> {
+    match name {
+        GeneralName::DNSName(name) => Some(name.to_string()),
+        GeneralName::RFC822Name(email) => Some(email.to_string()),
+        GeneralName::URI(uri) => Some(uri.to_string()),
+        GeneralName::IPAddress(ip) => match ip.len() {
+            4 => Some(IpAddr::from(<[u8; 4]>::try_from(*ip).ok()?).to_string()),
+            16 => Some(IpAddr::from(<[u8; 16]>::try_from(*ip).ok()?).to_string()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+fn validity(not_before: i64, not_after: i64, now: i64) -> Validity {
+    if now < not_before {
+        Validity::NotYetValid
+    } else if now > not_after {
+        Validity::Expired {
+            days_ago: (now - not_after) / SECONDS_PER_DAY,
+        }
+    } else {
+        Validity::Valid {
+            days_left: (not_after - now) / SECONDS_PER_DAY,
+        }
+    }
+}
+
+fn fingerprint(der: &[u8]) -> String {
+    let digest = digest::digest(&digest::SHA256, der);
+    digest.as_ref().iter().map(|byte| format!("{:02X}", byte)).collect::<Vec<_>>().join(":")
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{fingerprint, validity, Validity, SECONDS_PER_DAY};
+
+    #[test]
+    fn days_left() {
+        let now = 1_000 * SECONDS_PER_DAY;
+        assert_eq!(validity(now - 10, now + 30 * SECONDS_PER_DAY + 10, now), Validity::Valid { days_left: 30 });
+        assert_eq!(validity(now - 100 * SECONDS_PER_DAY, now - 2 * SECONDS_PER_DAY, now), Validity::Expired { days_ago: 2 });
+        assert_eq!(validity(now + 10, now + 100, now), Validity::NotYetValid);
+    }
+
+    #[test]
+    fn sha256_fingerprint() {
+        assert_eq!(
+            fingerprint(b"abc"),
+            "BA:78:16:BF:8F:01:CF:EA:41:41:40:DE:5D:AE:22:23:B0:03:61:A3:96:17:7A:9C:B4:10:FF:61:F2:00:15:AD"
+        );
+    }
+}
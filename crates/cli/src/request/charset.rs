@@ -0,0 +1,76 @@
+use super::HeaderMap;
+use encoding_rs::{Encoding, UTF_8};
+use reqwest::header::CONTENT_TYPE;
+use std::borrow::Cow;
+
+pub type Charset = &'static Encoding;
+
+// The WHATWG labels, e.g. latin1 and iso-8859-1 are windows-1252, utf-16 is utf-16le
+pub fn from_label(label: &str) -> Option<Charset> {
+    Encoding::for_label(label.trim().trim_matches('"').as_bytes())
+}
+
+pub fn from_headers(headers: &HeaderMap) -> Option<Charset> {
+    from_content_type(headers.get(CONTENT_TYPE)?.to_str().ok()?)
+}
+
+fn from_content_type(content_type: &str) -> Option<Charset> {
+    content_type.split(';').skip(1).find_map(|parameter| {
+        let (name, value) = parameter.split_once('=')?;
+        if name.trim().eq_ignore_ascii_case("charset") {
+            from_label(value)
+        } else {
+            None
+        }
+    })
+}
+
+// A BOM wins over the charset, returns None if the bytes are (supposed to be) UTF-8 without BOM
+pub fn decode(bytes: &[u8], charset: Option<Charset>) -> Option<Cow<'_, str>> {
+    let encoding = match Encoding::for_bom(bytes) {
+        Some((encoding, _)) => encoding,
+        None => charset.filter(|charset| *charset != UTF_8)?,
+    };
+    Some(encoding.decode(bytes).0)
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, from_content_type, from_label};
+    use encoding_rs::{SHIFT_JIS, UTF_16LE, UTF_8, WINDOWS_1252};
+
+    #[test]
+    fn labels() {
+        assert_eq!(from_label("ISO-8859-1"), Some(WINDOWS_1252));
+        assert_eq!(from_label("latin1"), Some(WINDOWS_1252));
+        assert_eq!(from_label("Shift_JIS"), Some(SHIFT_JIS));
+        assert_eq!(from_label("utf-16"), Some(UTF_16LE));
+        assert_eq!(from_label("\"utf-8\""), Some(UTF_8));
+        assert_eq!(from_label("unknown"), None);
+    }
+
+    #[test]
+    fn content_type() {
+        assert_eq!(from_content_type("text/plain; charset=iso-8859-1"), Some(WINDOWS_1252));
+        assert_eq!(from_content_type("application/json;CHARSET=\"Shift_JIS\""), Some(SHIFT_JIS));
+        assert_eq!(from_content_type("application/json"), None);
+        assert_eq!(from_content_type("charset=utf-8"), None);
+    }
+
+    #[test]
+    fn decoded() {
+        assert_eq!(decode(b"caf\xe9", Some(WINDOWS_1252)).unwrap(), "café");
+        assert_eq!(decode(b"\x93\xfa\x96\x7b", Some(SHIFT_JIS)).unwrap(), "日本");
+        assert_eq!(decode(b"\xff\xfe{\0}\0", None).unwrap(), "{}");
+        assert_eq!(decode(b"\xfe\xff\0{\0}", Some(WINDOWS_1252)).unwrap(), "{}");
+        assert_eq!(decode(b"\xef\xbb\xbf{}", None).unwrap(), "{}");
+    }
+
+    #[test]
+    fn not_decoded() {
+        assert_eq!(decode(b"caf\xc3\xa9", None), None);
+        assert_eq!(decode(b"caf\xc3\xa9", Some(UTF_8)), None);
+    }
+}
@@ -0,0 +1,164 @@
+use super::date::{duration, http_date, utc_date};
+
+// A Set-Cookie header, the attributes that aren't set are None or false
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub struct Cookie {
+    pub name: String,
+    pub value: String,
+    pub domain: Option<String>,
+    pub path: Option<String>,
+    pub expires: Option<i64>,
+    pub secure: bool,
+    pub http_only: bool,
+    pub same_site: Option<String>,
+}
+
+// For example: id=a3fWa; Expires=Wed, 21 Oct 2026 07:28:00 GMT; Path=/; Secure; HttpOnly
+pub fn parse(header: &str, now: i64) -> Option<Cookie> {
+    let mut attributes = header.split(';').map(str::trim);
+    let (name, value) = attributes.next()?.split_once('=')?;
+    let mut cookie = Cookie {
+        name: name.trim().to_string(),
+        value: value.trim().trim_matches('"').to_string(),
+        domain: None,
+        path: None,
+        expires: None,
+        secure: false,
+        http_only: false,
+        same_site: None,
+    };
+    if cookie.name.is_empty() {
+        return None;
+    }
+    let mut max_age = None;
+    for attribute in attributes {
+        let (key, value) = attribute.split_once('=').map_or((attribute, ""), |(key, value)| (key.trim(), value.trim()));
+        match key.to_ascii_lowercase().as_str() {
+            "domain" if !value.is_empty() => cookie.domain = Some(value.trim_start_matches('.').to_string()),
+            "path" if !value.is_empty() => cookie.path = Some(value.to_string()),
+            "expires" => cookie.expires = cookie.expires.or_else(|| http_date(value)),
+            "max-age" => max_age = value.parse::<i64>().ok(),
+            "secure" => cookie.secure = true,
+            "httponly" => cookie.http_only = true,
+            "samesite" => cookie.same_site = Some(value.to_string()),
+            _ => {}
+        }
+    }
+    // Max-Age wins over Expires
+    if let Some(max_age) = max_age {
+        cookie.expires = Some(now.saturating_add(max_age));
+    }
+    Some(cookie)
+}
+
+impl Cookie {
+    // The date and how long until it expires, a cookie without Expires and Max-Age is a session cookie
+    pub fn expiry(&self, now: i64) -> String {
+        match self.expires {
+            Some(expires) if expires <= now => format!("{} (expired, the cookie is deleted)", utc_date(expires)),
+            Some(expires) => format!("{} (in {})", utc_date(expires), duration(expires.saturating_sub(now))),
+            None => "session".to_string(),
+        }
+    }
+
+    // The combinations that the browsers reject or that aren't safe
+    pub fn warnings(&self, https: bool) -> Vec<String> {
+        let mut warnings = Vec::new();
+        let same_site = self.same_site.as_deref();
+        if same_site.is_some_and(|same_site| same_site.eq_ignore_ascii_case("none")) && !self.secure {
+            warnings.push("SameSite=None without Secure, the browsers reject the cookie".to_string());
+        }
+        if let Some(same_site) = same_site.filter(|same_site| !["strict", "lax", "none"].contains(&same_site.to_ascii_lowercase().as_str())) {
+            warnings.push(format!("SameSite={} isn't valid (Strict, Lax or None), the browsers use Lax", same_site));
+        }
+        if self.secure && !https {
+            warnings.push("Secure set over HTTP, the browsers ignore the cookie".to_string());
+        }
+        if self.name.starts_with("__Secure-") && !self.secure {
+            warnings.push("the __Secure- prefix requires Secure".to_string());
+        }
+        if self.name.starts_with("__Host-") && (!self.secure || self.domain.is_some() || self.path.as_deref() != Some("/")) {
+            warnings.push("the __Host- prefix requires Secure, Path=/ and no Domain".to_string());
+        }
+        // An empty value deletes the cookie (e.g. a logout)
+        if !self.http_only && !self.value.is_empty() && is_session_id(&self.name) {
+            warnings.push("no HttpOnly, the session can be read by JavaScript".to_string());
+        }
+        warnings
+    }
+}
+
+// The usual names of the session cookies (e.g. JSESSIONID, PHPSESSID, connect.sid, session_id)
+fn is_session_id(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    name.contains("sess") || name.ends_with(".sid") || name == "sid"
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Cookie};
+
+    const NOW: i64 = 1_792_395_000;
+
+    #[test]
+    fn parsed() {
+        let cookie = parse(
+            "id=a3fWa; Expires=Wed, 21 Oct 2026 07:28:00 GMT; Domain=.example.com; Path=/; Secure; HttpOnly; SameSite=Lax",
+            NOW,
+        );
+        assert_eq!(
+            cookie,
+            Some(Cookie {
+                name: "id".into(),
+                value: "a3fWa".into(),
+                domain: Some("example.com".into()),
+                path: Some("/".into()),
+                expires: Some(1_792_567_680),
+                secure: true,
+                http_only: true,
+                same_site: Some("Lax".into()),
+            })
+        );
+        let cookie = parse("theme=\"dark\"", NOW).unwrap();
+        assert_eq!((cookie.value.as_str(), cookie.expires), ("dark", None));
+        assert!(parse("no value", NOW).is_none());
+        assert!(parse("=value", NOW).is_none());
+    }
+
+    #[test]
+    fn expiry() {
+        let cookie = parse("id=1; Expires=Wed, 21 Oct 2026 07:28:00 GMT; Max-Age=3600", NOW).unwrap();
+        assert_eq!(cookie.expiry(NOW), "2026-10-19 08:30:00 UTC (in 1 hour)");
+        let cookie = parse("id=; Max-Age=0", NOW).unwrap();
+        assert_eq!(cookie.expiry(NOW), "2026-10-19 07:30:00 UTC (expired, the cookie is deleted)");
+        assert_eq!(parse("id=1", NOW).unwrap().expiry(NOW), "session");
+        let cookie = parse("id=1; Max-Age=9223372036854775807", NOW).unwrap();
+        assert_eq!(cookie.expires, Some(i64::MAX));
+        assert!(cookie.expiry(NOW).ends_with("days)"));
+        let cookie = parse("id=1; Max-Age=-9223372036854775808", NOW).unwrap();
+        assert!(cookie.expiry(NOW).ends_with("(expired, the cookie is deleted)"));
+    }
+
+    #[test]
+    fn warnings() {
+        let warnings = |header: &str, https: bool| parse(header, NOW).unwrap().warnings(https);
+        assert!(warnings("id=1; Secure; HttpOnly; SameSite=None", true).is_empty());
+        assert_eq!(warnings("id=1; SameSite=None", true), vec!["SameSite=None without Secure, the browsers reject the cookie"]);
+        assert_eq!(
+            warnings("id=1; SameSite=Loose", true),
+            vec!["SameSite=Loose isn't valid (Strict, Lax or None), the browsers use Lax"]
+        );
+        assert_eq!(warnings("id=1; Secure", false), vec!["Secure set over HTTP, the browsers ignore the cookie"]);
+        assert_eq!(warnings("__Secure-id=1", true), vec!["the __Secure- prefix requires Secure"]);
+        assert_eq!(
+            warnings("__Host-id=1; Secure; Path=/; Domain=example.com", true),
+            vec!["the __Host- prefix requires Secure, Path=/ and no Domain"]
+        );
+        assert!(warnings("__Host-id=1; Secure; Path=/", true).is_empty());
+        assert_eq!(warnings("JSESSIONID=1; Path=/", true), vec!["no HttpOnly, the session can be read by JavaScript"]);
+        assert!(warnings("JSESSIONID=; Max-Age=0", true).is_empty());
+        assert!(warnings("theme=dark", true).is_empty());
+    }
+}
@@ -0,0 +1,134 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const MONTHS: [&str; 12] = ["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+
+pub fn now() -> i64 {
+    now_millis() / 1000
+}
+
+pub fn now_millis() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|now| now.as_millis() as i64).unwrap_or(0)
+}
+
+pub fn duration(seconds: i64) -> String {
+    let (count, unit) = match seconds {
+        seconds if seconds < 60 => (seconds, "second"),
+        seconds if seconds < 3600 => (seconds / 60, "minute"),
+        seconds if seconds < 86400 => (seconds / 3600, "hour"),
+        seconds => (seconds / 86400, "day"),
+    };
+    format!("{} {}{}", count, unit, if count == 1 { "" } else { "s" })
+}
+
+// For example 2026-10-19 07:28:00 UTC
+pub fn utc_date(timestamp: i64) -> String {
+    let (year, month, day, seconds) = civil_from_timestamp(timestamp);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+// For example 2026-10-19T07:28:00.123Z (ISO 8601, e.g. for the HAR files)
+pub fn iso_date(timestamp_millis: i64) -> String {
+    let (year, month, day, seconds) = civil_from_timestamp(timestamp_millis.div_euclid(1000));
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60,
+        timestamp_millis.rem_euclid(1000)
+    )
+}
+
+// For example: Wed, 21 Oct 2026 07:28:00 GMT, or the obsolete Wednesday, 21-Oct-26 07:28:00 GMT
+pub fn http_date(date: &str) -> Option<i64> {
+    let date = date.split_once(',').map_or(date, |(_, date)| date);
+    let mut parts = date.split(|c: char| c.is_whitespace() || c == '-').filter(|part| !part.is_empty());
+    let day = parts.next()?.parse::<i64>().ok()?;
+    let month = parts.next()?.to_ascii_lowercase();
+    let month = MONTHS.iter().position(|name| month.starts_with(name))? as i64 + 1;
+    let year = match parts.next()?.parse::<i64>().ok()? {
+        year if year < 70 => year + 2000,
+        year if year < 100 => year + 1900,
+        year => year,
+    };
+    let mut time = parts.next()?.split(':').map(|part| part.parse::<i64>().ok());
+    let (hours, minutes, seconds) = (time.next()??, time.next()??, time.next()??);
+    // The years before 1601 are invalid in the cookies (RFC 6265), and the bound keeps the seconds from overflowing
+    if !(1601..=9999).contains(&year) || !(1..=31).contains(&day) || hours > 23 || minutes > 59 || seconds > 60 {
+        return None;
+    }
+    Some(days_from_civil(year, month, day) * 86400 + hours * 3600 + minutes * 60 + seconds)
+}
+
+// The year, month, day and seconds of the day
+fn civil_from_timestamp(timestamp: i64) -> (i64, i64, i64, i64) {
+    let days = timestamp.div_euclid(86400);
+    let seconds = timestamp.rem_euclid(86400);
+    // Gregorian calendar from the number of days since 1970-01-01 (http://howardhinnant.github.io/date_algorithms.html)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day, seconds)
+}
+
+// The number of days since 1970-01-01, the inverse of the above
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let shifted_month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * shifted_month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{http_date, iso_date, utc_date};
+
+    const NOW: i64 = 1_792_395_000;
+
+    #[test]
+    fn utc_dates() {
+        assert_eq!(utc_date(0), "1970-01-01 00:00:00 UTC");
+        assert_eq!(utc_date(951_782_400), "2000-02-29 00:00:00 UTC");
+        assert_eq!(utc_date(NOW), "2026-10-19 07:30:00 UTC");
+        assert_eq!(utc_date(-86_400), "1969-12-31 00:00:00 UTC");
+    }
+
+    #[test]
+    fn iso_dates() {
+        assert_eq!(iso_date(0), "1970-01-01T00:00:00.000Z");
+        assert_eq!(iso_date(NOW * 1000 + 42), "2026-10-19T07:30:00.042Z");
+    }
+
+    #[test]
+    fn http_dates() {
+        assert_eq!(http_date("Thu, 01 Jan 1970 00:00:00 GMT"), Some(0));
+        assert_eq!(http_date("Tue, 29 Feb 2000 00:00:00 GMT"), Some(951_782_400));
+        assert_eq!(http_date("Monday, 19-Oct-26 07:30:00 GMT"), Some(NOW));
+        assert_eq!(http_date("Mon, 19-Oct-2026 07:30:00 GMT"), Some(NOW));
+        assert_eq!(http_date("tomorrow"), None);
+        assert_eq!(http_date("Mon, 19 Oct 2026 25:00:00 GMT"), None);
+        assert_eq!(http_date("Wed, 21 Oct 99999999999999 07:28:00 GMT"), None);
+        assert_eq!(http_date("Sat, 01 Jan 1600 00:00:00 GMT"), None);
+    }
+}
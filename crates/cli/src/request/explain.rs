@@ -0,0 +1,173 @@
+use super::{HeaderMap, StatusCode};
+use regex::Regex;
+use reqwest::header::{HeaderName, ALLOW, PROXY_AUTHENTICATE, RETRY_AFTER, WWW_AUTHENTICATE};
+
+// A one-line description of a status code, for the people who don't know them by heart
+pub fn status(status: StatusCode) -> &'static str {
+    match status.as_u16() {
+        100 => "the server has received the request headers, the client can send the body",
+        101 => "the server switches to the protocol of the Upgrade header",
+        103 => "some headers are sent before the final response, for example to preload resources",
+        200 => "the request has succeeded",
+        201 => "the request has succeeded and a new resource has been created (see the Location header)",
+        202 => "the request has been accepted but isn't processed yet",
+        204 => "the request has succeeded and there is no body",
+        206 => "only the part of the Range header is sent",
+        301 => "the resource has moved to the URL of the Location header for good",
+        302 => "the resource is at the URL of the Location header for now",
+        303 => "the result is at the URL of the Location header, to get with GET",
+        304 => "the cached version of the resource is still valid, there is no body",
+        307 => "the resource is at the URL of the Location header for now, to request with the same method and body",
+        308 => "the resource has moved to the URL of the Location header for good, to request with the same method and body",
+        400 => "the server can't process the request because of a client error (e.g. a malformed syntax or an invalid payload)",
+        401 => "the request isn't authenticated, the credentials are missing or invalid (see the WWW-Authenticate header)",
+        402 => "a payment is required to access the resource",
+        403 => "the client isn't allowed to access the resource, even if authenticated",
+        404 => "the server can't find the resource",
+        405 => "the resource doesn't support the method (see the Allow header)",
+        406 => "there is no content matching the Accept headers of the request",
+        407 => "the proxy requires an authentication (see the Proxy-Authenticate header)",
+        408 => "the server has timed out waiting for the request",
+        409 => "the request conflicts with the current state of the resource",
+        410 => "the resource has been deleted for good",
+        411 => "the request requires a Content-Length header",
+        412 => "a condition of the request headers (e.g. If-Match) isn't met",
+        413 => "the request body is larger than what the server accepts",
+        414 => "the URL is longer than what the server accepts",
+        415 => "the server doesn't support the Content-Type of the request body",
+        416 => "the part of the Range header is outside of the resource",
+        417 => "the server can't meet the Expect header of the request",
+        418 => "the server is a teapot and refuses to brew coffee (an April Fools' joke)",
+        422 => "the request body is well-formed but invalid (e.g. a validation error)",
+        425 => "the server doesn't want to process a request that might be replayed",
+        426 => "the client must switch to the protocol of the Upgrade header",
+        428 => "the request must be conditional (e.g. with If-Match) to avoid conflicts",
+        429 => "too many requests have been sent in a given amount of time, the client is rate limited (see the Retry-After header)",
+        431 => "the request headers are too large",
+        451 => "the resource is unavailable for legal reasons",
+        500 => "the server has encountered an unexpected error",
+        501 => "the server doesn't support the functionality required by the request (e.g. the method)",
+        502 => "a gateway or proxy has received an invalid response from the upstream server",
+        503 => "the server can't handle the request for now, e.g. overloaded or down for maintenance (see the Retry-After header)",
+        504 => "a gateway or proxy hasn't received a response from the upstream server in time",
+        505 => "the server doesn't support the HTTP version of the request",
+        _ if status.is_informational() => "an informational response, the request is in progress",
+        _ if status.is_success() => "the request has succeeded",
+        _ if status.is_redirection() => "a redirection, the client must take another action to complete the request",
+        _ if status.is_client_error() => "a client error, the request is invalid or can't be fulfilled",
+        _ if status.is_server_error() => "a server error, the server has failed to fulfil a valid request",
+        _ => "a non-standard status code",
+    }
+}
+
+// The headers that tell what to do next, with a description of their value
+pub fn headers(headers: &HeaderMap) -> Vec<(&HeaderName, String)> {
+    let mut explanations = Vec::new();
+    for (name, value) in headers {
+        let value = match value.to_str() {
+            Ok(value) => value.trim(),
+            Err(_) => continue,
+        };
+        let explanation = if name == RETRY_AFTER {
+            retry_after(value)
+        } else if name == WWW_AUTHENTICATE {
+            authenticate(value, "Authorization")
+        } else if name == PROXY_AUTHENTICATE {
+            authenticate(value, "Proxy-Authorization")
+        } else if name == ALLOW {
+            format!("the allowed methods are {}", value)
+        } else {
+            continue;
+        };
+        explanations.push((name, explanation));
+    }
+    explanations
+}
+
+// A number of seconds or an HTTP date
+fn retry_after(value: &str) -> String {
+    match value.parse::<u64>() {
+        Ok(seconds) if seconds >= 120 => format!("retry in {} seconds (about {} minutes)", seconds, (seconds + 30) / 60),
+        Ok(1) => "retry in 1 second".to_string(),
+        Ok(seconds) => format!("retry in {} seconds", seconds),
+        Err(_) => format!("retry after {}", value),
+    }
+}
+
+// For example: Bearer realm="api", error="invalid_token", error_description="The token has expired"
+fn authenticate(value: &str, request_header: &str) -> String {
+    let scheme = value.split_whitespace().next().unwrap_or(value);
+    let mut explanation = format!("authenticate with the {} scheme in the {} header", scheme, request_header);
+    let params = Regex::new(r#"(\w+)\s*=\s*(?:"([^"]*)"|([^\s,]+))"#).unwrap();
+    let param = |key: &str| {
+        params
+            .captures_iter(value)
+            .find(|captures| captures[1].eq_ignore_ascii_case(key))
+            .and_then(|captures| captures.get(2).or_else(|| captures.get(3)))
+            .map(|value| value.as_str().to_string())
+    };
+    if let Some(realm) = param("realm") {
+        explanation.push_str(&format!(" (realm \"{}\")", realm));
+    }
+    if let Some(error) = param("error") {
+        explanation.push_str(&format!(", the error is {}", error));
+        if let Some(description) = param("error_description") {
+            explanation.push_str(&format!(": {}", description));
+        }
+    }
+    explanation
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{headers, status};
+    use crate::request::{HeaderMap, StatusCode};
+    use reqwest::header::{HeaderValue, ALLOW, CONTENT_TYPE, RETRY_AFTER, WWW_AUTHENTICATE};
+
+    #[test]
+    fn status_codes() {
+        assert_eq!(status(StatusCode::NOT_FOUND), "the server can't find the resource");
+        assert_eq!(status(StatusCode::from_u16(299).unwrap()), "the request has succeeded");
+        assert_eq!(status(StatusCode::from_u16(499).unwrap()), "a client error, the request is invalid or can't be fulfilled");
+        assert_eq!(status(StatusCode::from_u16(999).unwrap()), "a non-standard status code");
+    }
+
+    #[test]
+    fn retry_after() {
+        let mut map = HeaderMap::new();
+        map.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        map.insert(RETRY_AFTER, HeaderValue::from_static("30"));
+        assert_eq!(headers(&map), vec![(&RETRY_AFTER, "retry in 30 seconds".to_string())]);
+
+        map.insert(RETRY_AFTER, HeaderValue::from_static("600"));
+        assert_eq!(headers(&map)[0].1, "retry in 600 seconds (about 10 minutes)");
+
+        map.insert(RETRY_AFTER, HeaderValue::from_static("Wed, 21 Oct 2026 07:28:00 GMT"));
+        assert_eq!(headers(&map)[0].1, "retry after Wed, 21 Oct 2026 07:28:00 GMT");
+    }
+
+    #[test]
+    fn authenticate() {
+        let mut map = HeaderMap::new();
+        map.insert(WWW_AUTHENTICATE, HeaderValue::from_static("Basic realm=\"staging\", charset=\"UTF-8\""));
+        assert_eq!(headers(&map)[0].1, "authenticate with the Basic scheme in the Authorization header (realm \"staging\")");
+
+        map.insert(
+            WWW_AUTHENTICATE,
+            HeaderValue::from_static("Bearer realm=\"api\", error=invalid_token, error_description=\"The token has expired\""),
+        );
+        assert_eq!(
+            headers(&map)[0].1,
+            "authenticate with the Bearer scheme in the Authorization header (realm \"api\"), the error is invalid_token: The token has expired"
+        );
+    }
+
+    #[test]
+    fn allow() {
+        let mut map = HeaderMap::new();
+        map.insert(ALLOW, HeaderValue::from_static("GET, HEAD"));
+        assert_eq!(headers(&map), vec![(&ALLOW, "the allowed methods are GET, HEAD".to_string())]);
+    }
+}
@@ -1,17 +1,37 @@
-mod body;
-mod certificate;
+pub(crate) mod body;
+pub(crate) mod certificate;
+pub(crate) mod charset;
+mod connection;
+pub(crate) mod cookie;
+pub(crate) mod date;
+pub(crate) mod explain;
+pub(crate) mod jwt;
+mod response;
+pub(crate) mod timing;
+pub(crate) mod tls;
 
 pub(crate) mod header;
 pub(crate) mod headers;
 use crate::core::{Error, Result, Workspace};
-use body::Body;
+use body::{build_body, Body};
+use connection::Client;
 use std::time::Duration;
+use timing::Timer;
+use tls::TlsRecorder;
 
-pub type Response = reqwest::blocking::Response;
+pub use response::Response;
 pub type Method = reqwest::Method;
 pub type HeaderMap = reqwest::header::HeaderMap;
+pub type StatusCode = reqwest::StatusCode;
+
+pub fn execute(args: &Workspace, req_number: u8, headers: &HeaderMap, timer: Option<&Timer>, recorder: Option<&TlsRecorder>) -> Result<Response> {
+    let url = &args.urls[req_number as usize];
+    // The DNS lookup, the connection and the TLS handshake can't be observed through reqwest
+    if timer.is_some() || recorder.is_some() {
+        let mut client = Client::new(tls::client_config(args, recorder)?, timer, recorder);
+        return client.execute(args.method.clone(), url, headers, build_body(args));
+    }
 
-pub fn execute(args: &Workspace, req_number: u8, headers: &HeaderMap) -> Result<Response> {
     let mut client_builder = reqwest::blocking::Client::builder()
         .default_headers(headers.clone())
         .gzip(false)
@@ -22,11 +42,19 @@ pub fn execute(args: &Workspace, req_number: u8, headers: &HeaderMap) -> Result<
         client_builder = client_builder.add_root_certificate(cert);
     }
 
+    if args.flags.insecure {
+        client_builder = client_builder.danger_accept_invalid_certs(true);
+    }
+
+    // reqwest can't skip the hostname verification with rustls
+    if args.flags.insecure_hostname {
+        client_builder = client_builder.use_preconfigured_tls(tls::client_config(args, None)?);
+    }
+
     let client = client_builder.build()?;
     let method = args.method.clone();
-    let url = &args.urls[req_number as usize];
     let response = client.request(method, url).body_if_items(args).send()?;
-    Ok(response)
+    Ok(Response::from(response))
 }
 
 impl From<reqwest::Error> for Error {
@@ -0,0 +1,55 @@
+use super::{HeaderMap, StatusCode};
+use reqwest::Version;
+use std::io::{self, Read};
+use url::Url;
+
+// A response of reqwest, or of a connection of our own (see connection), with its body still to read
+pub struct Response {
+    status: StatusCode,
+    version: Version,
+    url: Url,
+    headers: HeaderMap,
+    body: Box<dyn Read>,
+}
+
+impl Response {
+    pub fn new(status: StatusCode, version: Version, url: Url, headers: HeaderMap, body: Box<dyn Read>) -> Self {
+        Self {
+            status,
+            version,
+            url,
+            headers,
+            body,
+        }
+    }
+
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn version(&self) -> Version {
+        self.version
+    }
+
+    // The URL of the last redirection
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+}
+
+impl Read for Response {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.body.read(buf)
+    }
+}
+
+impl From<reqwest::blocking::Response> for Response {
+    fn from(response: reqwest::blocking::Response) -> Self {
+        let (status, version, url, headers) = (response.status(), response.version(), response.url().clone(), response.headers().clone());
+        Self::new(status, version, url, headers, Box::new(response))
+    }
+}
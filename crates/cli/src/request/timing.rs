@@ -0,0 +1,169 @@
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Timing {
+    pub dns: Option<Duration>,
+    pub connect: Option<Duration>,
+    // Not available for the http URLs
+    pub tls: Option<Duration>,
+    pub first_byte: Duration,
+    pub download: Duration,
+    pub total: Duration,
+    pub size: usize,
+}
+
+// Collects the instants of the different phases of a request, marked by the connection (see connection). The phases
+// of the connection are the ones of the first connection, a redirection to another host opens another one
+#[derive(Clone)]
+pub struct Timer {
+    marks: Arc<Mutex<Marks>>,
+}
+
+#[derive(Clone, Copy)]
+struct Marks {
+    start: Instant,
+    dns: Option<Instant>,
+    connect: Option<Instant>,
+    tls: Option<Instant>,
+    first_byte: Option<Instant>,
+}
+
+impl Timer {
+    pub fn start() -> Timer {
+        Timer {
+            marks: Arc::new(Mutex::new(Marks::new(Instant::now()))),
+        }
+    }
+
+    pub fn dns_resolved(&self) {
+        self.mark(|marks, now| {
+            marks.dns.get_or_insert(now);
+        });
+    }
+
+    pub fn connected(&self) {
+        self.mark(|marks, now| {
+            marks.connect.get_or_insert(now);
+        });
+    }
+
+    pub fn handshaken(&self) {
+        self.mark(|marks, now| {
+            marks.tls.get_or_insert(now);
+        });
+    }
+
+    // The headers of the last response, after the redirections
+    pub fn first_byte(&self) {
+        self.mark(|marks, now| marks.first_byte = Some(now));
+    }
+
+    pub fn finish(&self, size: usize) -> Timing {
+        let marks = *self.marks.lock().unwrap();
+        marks.timing(Instant::now(), size)
+    }
+
+    fn mark<F: FnOnce(&mut Marks, Instant)>(&self, f: F) {
+        let now = Instant::now();
+        if let Ok(mut marks) = self.marks.lock() {
+            f(&mut marks, now);
+        }
+    }
+}
+
+impl Marks {
+    fn new(start: Instant) -> Marks {
+        Marks {
+            start,
+            dns: None,
+            connect: None,
+            tls: None,
+            first_byte: None,
+        }
+    }
+
+    fn timing(&self, end: Instant, size: usize) -> Timing {
+        let dns = self.dns.unwrap_or(self.start);
+        let connect = self.connect.unwrap_or(dns);
+        let first_byte = self.first_byte.unwrap_or(end);
+        Timing {
+            dns: self.dns.map(|dns| dns - self.start),
+            connect: self.connect.map(|connect| connect - dns),
+            tls: self.tls.map(|tls| tls - connect),
+            first_byte: first_byte - self.tls.unwrap_or(connect),
+            download: end - first_byte,
+            total: end - self.start,
+            size,
+        }
+    }
+}
+
+impl fmt::Debug for Timer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Timer")
+    }
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{Marks, Timer, Timing};
+    use std::time::{Duration, Instant};
+
+    fn ms(value: u64) -> Duration {
+        Duration::from_millis(value)
+    }
+
+    #[test]
+    fn https_phases() {
+        let start = Instant::now();
+        let marks = Marks {
+            dns: Some(start + ms(3)),
+            connect: Some(start + ms(15)),
+            tls: Some(start + ms(40)),
+            first_byte: Some(start + ms(100)),
+            ..Marks::new(start)
+        };
+        assert_eq!(
+            marks.timing(start + ms(120), 42),
+            Timing {
+                dns: Some(ms(3)),
+                connect: Some(ms(12)),
+                tls: Some(ms(25)),
+                first_byte: ms(60),
+                download: ms(20),
+                total: ms(120),
+                size: 42,
+            }
+        );
+    }
+
+    #[test]
+    fn http_phases() {
+        let start = Instant::now();
+        let marks = Marks {
+            dns: Some(start + ms(5)),
+            connect: Some(start + ms(15)),
+            first_byte: Some(start + ms(50)),
+            ..Marks::new(start)
+        };
+        let timing = marks.timing(start + ms(60), 10);
+        assert_eq!(timing.connect, Some(ms(10)));
+        assert_eq!(timing.tls, None);
+        assert_eq!(timing.first_byte, ms(35));
+        assert_eq!(timing.download, ms(10));
+    }
+
+    #[test]
+    fn first_connection_only() {
+        let timer = Timer::start();
+        timer.connected();
+        let first = timer.marks.lock().unwrap().connect;
+        timer.connected();
+        assert_eq!(timer.marks.lock().unwrap().connect, first);
+    }
+}
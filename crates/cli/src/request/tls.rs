@@ -0,0 +1,255 @@
+use super::certificate::{self, CertificateInfo};
+use super::date;
+use crate::core::{Error, Result, Workspace};
+use rustls::client::{ServerCertVerified, ServerCertVerifier, WebPkiVerifier};
+use rustls::{Certificate, ClientConfig, ClientConnection, OwnedTrustAnchor, RootCertStore, ServerName, SignatureScheme};
+use std::convert::TryFrom;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+use webpki::{EndEntityCert, SignatureAlgorithm, TlsServerTrustAnchors, TrustAnchor};
+
+// Same list as rustls
+static SUPPORTED_SIG_ALGS: &[&SignatureAlgorithm] = &[
+    &webpki::ECDSA_P256_SHA256,
+    &webpki::ECDSA_P256_SHA384,
+    &webpki::ECDSA_P384_SHA256,
+    &webpki::ECDSA_P384_SHA384,
+    &webpki::ED25519,
+    &webpki::RSA_PSS_2048_8192_SHA256_LEGACY_KEY,
+    &webpki::RSA_PSS_2048_8192_SHA384_LEGACY_KEY,
+    &webpki::RSA_PSS_2048_8192_SHA512_LEGACY_KEY,
+    &webpki::RSA_PKCS1_2048_8192_SHA256,
+    &webpki::RSA_PKCS1_2048_8192_SHA384,
+    &webpki::RSA_PKCS1_2048_8192_SHA512,
+    &webpki::RSA_PKCS1_3072_8192_SHA384,
+];
+
+#[cfg_attr(test, derive(Debug))]
+pub struct TlsInfo {
+    pub version: Option<String>,
+    pub cipher_suite: Option<String>,
+    pub alpn: Option<String>,
+    pub certificates: Vec<CertificateInfo>,
+}
+
+// The TLS parameters of the connection of the request, read from the connection once the handshake is done.
+// The certificates are also recorded by the verifier, so that they are kept even if they are rejected (that's
+// when they are the most useful)
+#[derive(Clone, Default)]
+pub struct TlsRecorder {
+    record: Arc<Mutex<Record>>,
+}
+
+#[derive(Default)]
+struct Record {
+    version: Option<String>,
+    cipher_suite: Option<String>,
+    alpn: Option<String>,
+    chain: Vec<Certificate>,
+}
+
+impl TlsRecorder {
+    // None without a TLS handshake (e.g. a http URL)
+    pub fn info(&self) -> Option<TlsInfo> {
+        let record = self.record.lock().ok()?;
+        if record.chain.is_empty() {
+            return None;
+        }
+        let now = date::now();
+        Some(TlsInfo {
+            version: record.version.clone(),
+            cipher_suite: record.cipher_suite.clone(),
+            alpn: record.alpn.clone(),
+            certificates: record.chain.iter().filter_map(|cert| certificate::describe(&cert.0, now)).collect(),
+        })
+    }
+
+    // A new connection (e.g. after a redirection to another host) replaces the previous one
+    pub fn connected(&self, connection: &ClientConnection) {
+        self.record(|record| {
+            record.version = connection.protocol_version().map(|version| version_name(&format!("{:?}", version)));
+            record.cipher_suite = connection.negotiated_cipher_suite().map(|suite| format!("{:?}", suite.suite()));
+            record.alpn = connection.alpn_protocol().map(|protocol| String::from_utf8_lossy(protocol).into_owned());
+            if let Some(chain) = connection.peer_certificates() {
+                record.chain = chain.to_vec();
+            }
+        });
+    }
+
+    fn record<F: FnOnce(&mut Record)>(&self, f: F) {
+        if let Ok(mut record) = self.record.lock() {
+            f(&mut record);
+        }
+    }
+}
+
+// Same settings as the rustls configuration built by reqwest, plus the verifier of the recorder
+pub fn client_config(ws: &Workspace, recorder: Option<&TlsRecorder>) -> Result<ClientConfig> {
+    let verifier = match recorder {
+        Some(recorder) => Arc::new(RecordingVerifier::new(verifier(ws)?, recorder.clone())),
+        None => verifier(ws)?,
+    };
+    let mut config = ClientConfig::builder()
+        .with_safe_defaults()
+        .with_custom_certificate_verifier(verifier)
+        .with_no_client_auth();
+    config.alpn_protocols = alpn_protocols();
+    Ok(config)
+}
+
+// The certificates are verified by rustls, or not at all (--insecure), or without the hostname (--insecure-hostname)
+fn verifier(ws: &Workspace) -> Result<Arc<dyn ServerCertVerifier>> {
+    if ws.flags.insecure {
+        Ok(Arc::new(AcceptAnyCertificate))
+    } else if ws.flags.insecure_hostname {
+        Ok(Arc::new(IgnoreHostname::new(ws)?))
+    } else {
+        Ok(Arc::new(WebPkiVerifier::new(root_cert_store(ws)?, None)))
+    }
+}
+
+fn root_cert_store(ws: &Workspace) -> Result<RootCertStore> {
+    let mut store = RootCertStore::empty();
+    let trust_anchors = webpki_roots::TLS_SERVER_ROOTS
+        .0
+        .iter()
+        .map(|anchor| OwnedTrustAnchor::from_subject_spki_name_constraints(anchor.subject, anchor.spki, anchor.name_constraints));
+    store.add_server_trust_anchors(trust_anchors);
+
+    if let Some(cafile) = ws.certificate_authority_file.as_ref() {
+        for der in certificate::load_der(cafile)? {
+            store
+                .add(&Certificate(der))
+                .map_err(|err| Error::Request(format!("invalid certificate {}: {}", cafile, err)))?;
+        }
+    }
+    Ok(store)
+}
+
+fn alpn_protocols() -> Vec<Vec<u8>> {
+    vec![b"h2".to_vec(), b"http/1.1".to_vec()]
+}
+
+// TLSv1_3 -> TLS 1.3
+fn version_name(version: &str) -> String {
+    match version.strip_prefix("TLSv") {
+        Some(number) => format!("TLS {}", number.replace('_', ".")),
+        None => version.to_string(),
+    }
+}
+
+struct AcceptAnyCertificate;
+
+impl ServerCertVerifier for AcceptAnyCertificate {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &Certificate,
+        _intermediates: &[Certificate],
+        _server_name: &ServerName,
+        _scts: &mut dyn Iterator<Item = &[u8]>,
+        _ocsp_response: &[u8],
+        _now: SystemTime,
+    ) -> std::result::Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+}
+
+// Verifies the chain of trust and the validity dates, but not the names the certificate is issued for
+struct IgnoreHostname {
+    cafile_certs: Vec<Vec<u8>>,
+}
+
+impl IgnoreHostname {
+    fn new(ws: &Workspace) -> Result<Self> {
+        let cafile_certs = match ws.certificate_authority_file.as_ref() {
+            Some(cafile) => certificate::load_der(cafile)?,
+            None => Vec::new(),
+        };
+        Ok(Self { cafile_certs })
+    }
+}
+
+impl ServerCertVerifier for IgnoreHostname {
+    fn verify_server_cert(
+        &self,
+        end_entity: &Certificate,
+        intermediates: &[Certificate],
+        _server_name: &ServerName,
+        _scts: &mut dyn Iterator<Item = &[u8]>,
+        _ocsp_response: &[u8],
+        now: SystemTime,
+    ) -> std::result::Result<ServerCertVerified, rustls::Error> {
+        let invalid = |err: webpki::Error| rustls::Error::InvalidCertificateData(format!("invalid peer certificate: {:?}", err));
+        let mut trust_anchors: Vec<TrustAnchor> = webpki_roots::TLS_SERVER_ROOTS
+            .0
+            .iter()
+            .map(|anchor| TrustAnchor {
+                subject: anchor.subject,
+                spki: anchor.spki,
+                name_constraints: anchor.name_constraints,
+            })
+            .collect();
+        for der in self.cafile_certs.iter() {
+            trust_anchors.push(TrustAnchor::try_from_cert_der(der).map_err(invalid)?);
+        }
+        let cert = EndEntityCert::try_from(end_entity.0.as_slice()).map_err(invalid)?;
+        let chain: Vec<&[u8]> = intermediates.iter().map(|cert| cert.0.as_slice()).collect();
+        let now = webpki::Time::try_from(now).map_err(|_| rustls::Error::FailedToGetCurrentTime)?;
+        cert.verify_is_valid_tls_server_cert(SUPPORTED_SIG_ALGS, &TlsServerTrustAnchors(&trust_anchors), &chain, now)
+            .map_err(invalid)?;
+        Ok(ServerCertVerified::assertion())
+    }
+}
+
+// The chain is recorded before being verified. The signatures are verified by the default methods of the trait,
+// like the verifiers above do
+struct RecordingVerifier {
+    inner: Arc<dyn ServerCertVerifier>,
+    recorder: TlsRecorder,
+}
+
+impl RecordingVerifier {
+    fn new(inner: Arc<dyn ServerCertVerifier>, recorder: TlsRecorder) -> Self {
+        Self { inner, recorder }
+    }
+}
+
+impl ServerCertVerifier for RecordingVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &Certificate,
+        intermediates: &[Certificate],
+        server_name: &ServerName,
+        scts: &mut dyn Iterator<Item = &[u8]>,
+        ocsp_response: &[u8],
+        now: SystemTime,
+    ) -> std::result::Result<ServerCertVerified, rustls::Error> {
+        let mut chain = vec![end_entity.clone()];
+        chain.extend_from_slice(intermediates);
+        self.recorder.record(|record| *record = Record { chain, ..Record::default() });
+        self.inner.verify_server_cert(end_entity, intermediates, server_name, scts, ocsp_response, now)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{version_name, TlsRecorder};
+
+    #[test]
+    fn no_info_without_handshake() {
+        assert!(TlsRecorder::default().info().is_none());
+    }
+
+    #[test]
+    fn version_names() {
+        assert_eq!(version_name("TLSv1_3"), "TLS 1.3");
+        assert_eq!(version_name("TLSv1_2"), "TLS 1.2");
+        assert_eq!(version_name("Unknown(0x0305)"), "Unknown(0x0305)");
+    }
+}
@@ -0,0 +1,64 @@
+use std::env;
+
+// The colours of an output, unless a flag says otherwise (https://no-color.org and https://bixense.com/clicolors)
+pub fn enabled(is_terminal: bool) -> bool {
+    policy(is_terminal, |name| env::var(name).ok()) && supported()
+}
+
+#[inline]
+fn supported() -> bool {
+    #[cfg(windows)]
+    return ansi_term::enable_ansi_support().is_ok();
+    #[cfg(not(windows))]
+    true
+}
+
+fn policy<V: Fn(&str) -> Option<String>>(is_terminal: bool, var: V) -> bool {
+    let is_set = |name| var(name).is_some_and(|value| !value.is_empty());
+    if is_set("NO_COLOR") {
+        false
+    } else if var("CLICOLOR_FORCE").is_some_and(|value| !value.is_empty() && value != "0") {
+        true
+    } else if var("CLICOLOR").as_deref() == Some("0") {
+        false
+    } else {
+        is_terminal && var("TERM").as_deref() != Some("dumb")
+    }
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::policy;
+
+    fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |name| vars.iter().find(|(key, _)| *key == name).map(|(_, value)| value.to_string())
+    }
+
+    #[test]
+    fn terminal() {
+        assert_eq!(policy(true, env(&[])), true);
+        assert_eq!(policy(false, env(&[])), false);
+        assert_eq!(policy(true, env(&[("TERM", "xterm-256color")])), true);
+        assert_eq!(policy(true, env(&[("TERM", "dumb")])), false);
+    }
+
+    #[test]
+    fn no_color() {
+        assert_eq!(policy(true, env(&[("NO_COLOR", "1")])), false);
+        assert_eq!(policy(true, env(&[("NO_COLOR", "")])), true);
+        assert_eq!(policy(false, env(&[("NO_COLOR", "1"), ("CLICOLOR_FORCE", "1")])), false);
+    }
+
+    #[test]
+    fn clicolor() {
+        assert_eq!(policy(true, env(&[("CLICOLOR", "0")])), false);
+        assert_eq!(policy(true, env(&[("CLICOLOR", "1")])), true);
+        assert_eq!(policy(false, env(&[("CLICOLOR", "1")])), false);
+        assert_eq!(policy(false, env(&[("CLICOLOR_FORCE", "1")])), true);
+        assert_eq!(policy(false, env(&[("CLICOLOR_FORCE", "0")])), false);
+        assert_eq!(policy(true, env(&[("CLICOLOR", "0"), ("CLICOLOR_FORCE", "1")])), true);
+        assert_eq!(policy(false, env(&[("CLICOLOR_FORCE", "1"), ("TERM", "dumb")])), true);
+    }
+}
@@ -1,4 +1,4 @@
-use super::{Render, enable_colors};
+use super::Render;
 use crate::theme::style::Color;
 use std::{
     fmt::Display,
@@ -7,18 +7,19 @@ use std::{
 
 pub struct ErrorRender<T> {
     message: T,
+    style_enabled: bool,
 }
 
 impl<T: Display> ErrorRender<T> {
-    pub fn new(message: T) -> Self {
-        Self { message }
+    pub fn new(message: T, style_enabled: bool) -> Self {
+        Self { message, style_enabled }
     }
 }
 
 impl<T: Display> Render for ErrorRender<T> {
     #[inline]
     fn is_style_active(&self) -> bool {
-        enable_colors()
+        self.style_enabled
     }
 
     #[inline]
@@ -0,0 +1,107 @@
+use super::Render;
+use crate::theme::DataTheme;
+use std::io::{Result, Write};
+
+const BYTES_PER_LINE: usize = 16;
+
+// The same layout as 'hexdump -C': offset, hex bytes in two groups of 8, then the printable characters
+pub struct HexdumpRender<'a> {
+    bytes: &'a [u8],
+    limit: Option<usize>,
+    theme: &'a dyn DataTheme,
+    style_enabled: bool,
+}
+
+impl<'a> HexdumpRender<'a> {
+    pub fn new(bytes: &'a [u8], limit: Option<usize>, theme: &'a dyn DataTheme, style_enabled: bool) -> Self {
+        Self {
+            bytes,
+            limit,
+            theme,
+            style_enabled,
+        }
+    }
+}
+
+impl<'a> Render for HexdumpRender<'a> {
+    #[inline]
+    fn is_style_active(&self) -> bool {
+        self.style_enabled
+    }
+
+    fn write<W: Write>(&self, writer: &mut W) -> Result<()> {
+        let shown = self.limit.map_or(self.bytes.len(), |limit| limit.min(self.bytes.len()));
+        // Each line ends with a new line
+        for (index, line) in self.bytes[..shown].chunks(BYTES_PER_LINE).enumerate() {
+            self.write_line(writer, index * BYTES_PER_LINE, line)?;
+            self.write_newline(writer)?;
+        }
+        let remaining = self.bytes.len() - shown;
+        if remaining > 0 {
+            writer.write_all(format!("… {} more bytes", remaining).as_bytes())?;
+            self.write_newline(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> HexdumpRender<'a> {
+    fn write_line<W: Write>(&self, writer: &mut W, offset: usize, line: &[u8]) -> Result<()> {
+        self.write_with_style(writer, format!("{:08x}", offset).as_bytes(), &self.theme.data_key())?;
+        writer.write_all(b" ")?;
+
+        let mut hex = String::with_capacity(3 * BYTES_PER_LINE + 1);
+        for index in 0..BYTES_PER_LINE {
+            if index % 8 == 0 {
+                hex.push(' ');
+            }
+            match line.get(index) {
+                Some(byte) => hex.push_str(&format!("{:02x} ", byte)),
+                None => hex.push_str("   "),
+            }
+        }
+        self.write_with_style(writer, hex.as_bytes(), &self.theme.data_number())?;
+
+        let ascii: String = line.iter().map(|byte| if byte.is_ascii_graphic() || *byte == b' ' { *byte as char } else { '.' }).collect();
+        writer.write_all(b" |")?;
+        self.write_with_style(writer, ascii.as_bytes(), &self.theme.data_string())?;
+        writer.write_all(b"|")
+    }
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::HexdumpRender;
+    use crate::shell::Render;
+    use crate::theme::default::DefaultTheme;
+    use crate::theme::Theme;
+
+    fn render(bytes: &[u8], limit: Option<usize>) -> String {
+        let theme = DefaultTheme::new().response();
+        let mut out = Vec::new();
+        HexdumpRender::new(bytes, limit, theme.as_data(), false).write(&mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn lines() {
+        assert_eq!(render(b"", None), "");
+        assert_eq!(
+            render(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR and more", None),
+            "00000000  89 50 4e 47 0d 0a 1a 0a  00 00 00 0d 49 48 44 52  |.PNG........IHDR|\n\
+             00000010  20 61 6e 64 20 6d 6f 72  65                       | and more|\n"
+        );
+    }
+
+    #[test]
+    fn limited() {
+        assert_eq!(
+            render(b"abcdef", Some(4)),
+            "00000000  61 62 63 64                                       |abcd|\n… 2 more bytes\n"
+        );
+        assert_eq!(render(b"abcdef", Some(0)), "… 6 more bytes\n");
+        assert_eq!(render(b"ab", Some(4)), "00000000  61 62                                             |ab|\n");
+    }
+}
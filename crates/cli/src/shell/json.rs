@@ -1,19 +1,29 @@
 use super::Render;
-use ansi_term::{Color as AnsiTermColor, Style};
+use crate::theme::DataTheme;
+use ansi_term::Style;
 use colored_json::{ColoredFormatter, CompactFormatter, PrettyFormatter, Styler};
 use serde::Serialize;
 use serde_json::ser::Formatter;
+use serde_json::{Map, Value};
 use std::io::{Result, Write};
 
 pub struct JsonRender<'a, T> {
     value: &'a T,
     compact: bool,
+    sort_keys: bool,
+    theme: &'a dyn DataTheme,
     style_enabled: bool,
 }
 
 impl<'a, T: Serialize> JsonRender<'a, T> {
-    pub fn new(value: &'a T, compact: bool, style_enabled: bool) -> Self {
-        Self { value, compact, style_enabled }
+    pub fn new(value: &'a T, compact: bool, sort_keys: bool, theme: &'a dyn DataTheme, style_enabled: bool) -> Self {
+        Self {
+            value,
+            compact,
+            sort_keys,
+            theme,
+            style_enabled,
+        }
     }
 }
 
@@ -38,22 +48,83 @@ impl<'a, T: Serialize> JsonRender<'a, T> {
     fn write_with_formatter<W: Write, F: Formatter>(&self, writer: W, formatter: F) -> Result<()> {
         let formatter = ColoredFormatter::with_styler(formatter, self.style());
         let mut serializer = serde_json::Serializer::with_formatter(writer, formatter);
-        self.value.serialize(&mut serializer)?;
+        if self.sort_keys {
+            sort_keys(serde_json::to_value(self.value)?).serialize(&mut serializer)?;
+        } else {
+            self.value.serialize(&mut serializer)?;
+        }
         Ok(())
     }
 
     #[inline]
     fn style(&self) -> Styler {
+        if !self.style_enabled {
+            return Styler {
+                object_brackets: Style::new(),
+                array_brackets: Style::new(),
+                key: Style::new(),
+                string_value: Style::new(),
+                integer_value: Style::new(),
+                float_value: Style::new(),
+                bool_value: Style::new(),
+                nil_value: Style::new(),
+                string_include_quotation: true,
+            };
+        }
+        let theme = self.theme;
         Styler {
             object_brackets: Style::new(),
-            array_brackets: Style::new().fg(AnsiTermColor::Red),
-            key: Style::new().fg(AnsiTermColor::Blue),
-            string_value: Style::new().fg(AnsiTermColor::Green),
-            integer_value: Style::new().fg(AnsiTermColor::Purple),
-            float_value: Style::new().fg(AnsiTermColor::Purple),
-            bool_value: Style::new().fg(AnsiTermColor::Yellow),
-            nil_value: Style::new().fg(AnsiTermColor::Cyan),
+            array_brackets: Style::from(&theme.data_bracket()),
+            key: Style::from(&theme.data_key()),
+            string_value: Style::from(&theme.data_string()),
+            integer_value: Style::from(&theme.data_number()),
+            float_value: Style::from(&theme.data_number()),
+            bool_value: Style::from(&theme.data_bool()),
+            nil_value: Style::from(&theme.data_null()),
             string_include_quotation: true,
         }
     }
 }
+
+// The maps keep the insertion order (preserve_order), so the keys are sorted for the display only
+pub fn sort_keys(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(String, Value)> = map.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(entries.into_iter().map(|(key, value)| (key, sort_keys(value))).collect::<Map<String, Value>>())
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(sort_keys).collect()),
+        value => value,
+    }
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::JsonRender;
+    use crate::shell::Render;
+    use crate::theme::default::DefaultTheme;
+    use crate::theme::Theme;
+    use serde_json::json;
+
+    fn render(value: &serde_json::Value, sort_keys: bool) -> String {
+        let theme = DefaultTheme::new().response();
+        let mut out = Vec::new();
+        JsonRender::new(value, true, sort_keys, theme.as_data(), false).write(&mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn keep_the_order() {
+        let value = json!({"b": 1, "a": {"d": 2, "c": 3}});
+        assert_eq!(render(&value, false), r#"{"b":1,"a":{"d":2,"c":3}}"#);
+    }
+
+    #[test]
+    fn sort_the_keys_recursively() {
+        let value = json!({"b": 1, "a": {"d": 2, "c": 3}, "e": [{"g": 4, "f": 5}]});
+        assert_eq!(render(&value, true), r#"{"a":{"c":3,"d":2},"b":1,"e":[{"f":5,"g":4}]}"#);
+    }
+}
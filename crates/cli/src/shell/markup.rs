@@ -0,0 +1,404 @@
+use super::Render;
+use crate::theme::MarkupTheme;
+use std::io::{Result, Write};
+
+const INDENT: &str = "  ";
+// The HTML elements that have no end tag
+const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source", "track", "wbr",
+];
+// The HTML elements whose content is not markup
+const RAW_TEXT_ELEMENTS: [&str; 2] = ["script", "style"];
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(Clone, Copy, PartialEq)]
+pub enum Markup {
+    Xml,
+    Html,
+}
+
+pub struct MarkupRender<'a> {
+    text: &'a str,
+    html: bool,
+    compact: bool,
+    format: bool,
+    theme: &'a dyn MarkupTheme,
+    style_enabled: bool,
+}
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(PartialEq)]
+enum Token<'a> {
+    Open {
+        name: &'a str,
+        attributes: Vec<Attribute<'a>>,
+        self_closing: bool,
+    },
+    Close(&'a str),
+    Text(&'a str),
+    // The content of <script>, <style>... and CDATA sections
+    Raw(&'a str),
+    // Comments, doctypes, XML declarations and processing instructions
+    Comment(&'a str),
+}
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(PartialEq)]
+struct Attribute<'a> {
+    name: &'a str,
+    value: Option<&'a str>,
+}
+
+impl Markup {
+    // XML includes SOAP and the other +xml media types
+    pub fn from_content_type(content_type: &str) -> Option<Markup> {
+        let media_type = content_type.split(';').next().unwrap_or_default().trim().to_ascii_lowercase();
+        match media_type.as_str() {
+            "text/html" | "application/xhtml+xml" => Some(Markup::Html),
+            "text/xml" | "application/xml" => Some(Markup::Xml),
+            media_type if media_type.ends_with("+xml") => Some(Markup::Xml),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> MarkupRender<'a> {
+    pub fn new(text: &'a str, markup: Markup, compact: bool, format: bool, theme: &'a dyn MarkupTheme, style_enabled: bool) -> Self {
+        Self {
+            text,
+            html: markup == Markup::Html,
+            compact,
+            format,
+            theme,
+            style_enabled,
+        }
+    }
+}
+
+impl<'a> Render for MarkupRender<'a> {
+    #[inline]
+    fn is_style_active(&self) -> bool {
+        self.style_enabled
+    }
+
+    fn write<W: Write>(&self, writer: &mut W) -> Result<()> {
+        let tokens = tokenize(self.text, self.html);
+        if !self.format {
+            for token in tokens.iter() {
+                self.write_token(writer, token, false)?;
+            }
+            Ok(())
+        } else if self.compact {
+            for token in tokens.iter() {
+                match token {
+                    // The spaces around the text of HTML elements matter (e.g. "Hi <b>there</b>")
+                    Token::Text(text) if self.html && !text.trim().is_empty() => {
+                        let start = if text.starts_with(char::is_whitespace) { " " } else { "" };
+                        let end = if text.ends_with(char::is_whitespace) { " " } else { "" };
+                        write!(writer, "{}{}{}", start, text.trim(), end)?;
+                    }
+                    token => self.write_token(writer, token, true)?,
+                }
+            }
+            Ok(())
+        } else {
+            self.write_indented(writer, &tokens)
+        }
+    }
+}
+
+impl<'a> MarkupRender<'a> {
+    fn write_indented<W: Write>(&self, writer: &mut W, tokens: &[Token]) -> Result<()> {
+        let mut depth = 0;
+        let mut first_line = true;
+        let mut index = 0;
+        while index < tokens.len() {
+            let token = &tokens[index];
+            index += 1;
+            if let Token::Text(text) | Token::Raw(text) = token {
+                if text.trim().is_empty() {
+                    continue;
+                }
+            }
+
+            if !first_line {
+                self.write_newline(writer)?;
+            }
+            first_line = false;
+            match token {
+                Token::Open { name, self_closing: false, .. } if !self.is_void(name) => {
+                    writer.write_all(INDENT.repeat(depth).as_bytes())?;
+                    self.write_token(writer, token, true)?;
+                    // An element that only contains a single line of text stays on one line
+                    match (tokens.get(index), tokens.get(index + 1)) {
+                        (Some(Token::Close(_)), _) => {
+                            self.write_token(writer, &tokens[index], true)?;
+                            index += 1;
+                        }
+                        (Some(Token::Text(text) | Token::Raw(text)), Some(Token::Close(_))) if !text.trim().contains('\n') => {
+                            self.write_token(writer, &tokens[index], true)?;
+                            self.write_token(writer, &tokens[index + 1], true)?;
+                            index += 2;
+                        }
+                        _ => depth += 1,
+                    }
+                }
+                Token::Close(_) => {
+                    depth = depth.saturating_sub(1);
+                    writer.write_all(INDENT.repeat(depth).as_bytes())?;
+                    self.write_token(writer, token, true)?;
+                }
+                Token::Text(text) | Token::Raw(text) => self.write_lines(writer, text, depth)?,
+                _ => {
+                    writer.write_all(INDENT.repeat(depth).as_bytes())?;
+                    self.write_token(writer, token, true)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // The lines are re-indented, keeping their relative indentation (e.g. a script)
+    fn write_lines<W: Write>(&self, writer: &mut W, text: &str, depth: usize) -> Result<()> {
+        let lines: Vec<&str> = text.lines().filter(|line| !line.trim().is_empty()).collect();
+        // Only the ASCII spaces and tabs count, so that the margin is a char boundary (e.g. before a no-break space)
+        let margin = lines.iter().map(|line| line.len() - line.trim_start_matches([' ', '\t']).len()).min().unwrap_or(0);
+        for (index, line) in lines.iter().enumerate() {
+            if index > 0 {
+                self.write_newline(writer)?;
+            }
+            writer.write_all(INDENT.repeat(depth).as_bytes())?;
+            writer.write_all(line[margin..].trim_end().as_bytes())?;
+        }
+        Ok(())
+    }
+
+    fn write_token<W: Write>(&self, writer: &mut W, token: &Token, trim: bool) -> Result<()> {
+        let theme = self.theme;
+        match token {
+            Token::Open { name, attributes, self_closing } => {
+                let tag_style = theme.markup_tag();
+                self.write_with_style(writer, format!("<{}", name).as_bytes(), &tag_style)?;
+                for attribute in attributes.iter() {
+                    writer.write_all(b" ")?;
+                    self.write_with_style(writer, attribute.name.as_bytes(), &theme.markup_attribute())?;
+                    if let Some(value) = attribute.value {
+                        writer.write_all(b"=")?;
+                        self.write_with_style(writer, value.as_bytes(), &theme.markup_value())?;
+                    }
+                }
+                self.write_with_style(writer, if *self_closing { b"/>" } else { b">" }, &tag_style)
+            }
+            Token::Close(name) => self.write_with_style(writer, format!("</{}>", name).as_bytes(), &theme.markup_tag()),
+            Token::Text(text) | Token::Raw(text) if trim => writer.write_all(text.trim().as_bytes()),
+            Token::Text(text) | Token::Raw(text) => writer.write_all(text.as_bytes()),
+            Token::Comment(comment) => self.write_with_style(writer, comment.as_bytes(), &theme.markup_comment()),
+        }
+    }
+
+    fn is_void(&self, name: &str) -> bool {
+        self.html && VOID_ELEMENTS.iter().any(|element| element.eq_ignore_ascii_case(name))
+    }
+}
+
+fn tokenize(text: &str, html: bool) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        if let Some(body) = rest.strip_prefix("<![CDATA[") {
+            let end = body.find("]]>").map(|end| end + "<![CDATA[]]>".len()).unwrap_or(rest.len());
+            tokens.push(Token::Raw(&rest[..end]));
+            rest = &rest[end..];
+        } else if rest.starts_with("<!--") {
+            let end = rest.find("-->").map(|end| end + 3).unwrap_or(rest.len());
+            tokens.push(Token::Comment(&rest[..end]));
+            rest = &rest[end..];
+        } else if rest.starts_with("<!") || rest.starts_with("<?") {
+            let end = rest.find('>').map(|end| end + 1).unwrap_or(rest.len());
+            tokens.push(Token::Comment(&rest[..end]));
+            rest = &rest[end..];
+        } else if let Some(close) = rest.strip_prefix("</") {
+            let end = close.find('>').unwrap_or(close.len());
+            tokens.push(Token::Close(close[..end].trim()));
+            rest = close.get(end + 1..).unwrap_or("");
+        } else if is_tag_start(rest) {
+            let (token, after) = open_tag(&rest[1..]);
+            rest = after;
+            if let Token::Open { name, self_closing: false, .. } = token {
+                if html && RAW_TEXT_ELEMENTS.iter().any(|element| element.eq_ignore_ascii_case(name)) {
+                    let end = find_ignore_case(rest, &format!("</{}", name)).unwrap_or(rest.len());
+                    tokens.push(token);
+                    tokens.push(Token::Raw(&rest[..end]));
+                    rest = &rest[end..];
+                    continue;
+                }
+            }
+            tokens.push(token);
+        } else {
+            // A '<' that doesn't start a tag is part of the text
+            let end = rest
+                .char_indices()
+                .skip(1)
+                .find(|(index, _)| is_tag_start(&rest[*index..]))
+                .map(|(index, _)| index)
+                .unwrap_or(rest.len());
+            tokens.push(Token::Text(&rest[..end]));
+            rest = &rest[end..];
+        }
+    }
+    tokens
+}
+
+fn is_tag_start(text: &str) -> bool {
+    let mut chars = text.chars();
+    chars.next() == Some('<') && chars.next().map(|c| c.is_alphabetic() || c == '/' || c == '!' || c == '?').unwrap_or(false)
+}
+
+// Parses "name attr="value" ...>" and returns the remaining text
+fn open_tag(text: &str) -> (Token<'_>, &str) {
+    let name_end = text.find(|c: char| c.is_whitespace() || c == '/' || c == '>').unwrap_or(text.len());
+    let name = &text[..name_end];
+    let mut rest = &text[name_end..];
+    let mut attributes = Vec::new();
+    let mut self_closing = false;
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            break;
+        } else if let Some(after) = rest.strip_prefix('>') {
+            rest = after;
+            break;
+        } else if let Some(after) = rest.strip_prefix("/>") {
+            self_closing = true;
+            rest = after;
+            break;
+        } else if let Some(after) = rest.strip_prefix('/') {
+            rest = after;
+            continue;
+        }
+
+        let name_end = rest.find(|c: char| c.is_whitespace() || c == '=' || c == '>' || c == '/').unwrap_or(rest.len());
+        let name = &rest[..name_end];
+        rest = rest[name_end..].trim_start();
+        let mut value = None;
+        if let Some(after) = rest.strip_prefix('=') {
+            let after = after.trim_start();
+            let end = match after.chars().next() {
+                Some(quote) if quote == '"' || quote == '\'' => after[1..].find(quote).map(|end| end + 2).unwrap_or(after.len()),
+                _ => after.find(|c: char| c.is_whitespace() || c == '>').unwrap_or(after.len()),
+            };
+            value = Some(&after[..end]);
+            rest = &after[end..];
+        }
+        attributes.push(Attribute { name, value });
+    }
+    (Token::Open { name, attributes, self_closing }, rest)
+}
+
+fn find_ignore_case(text: &str, pattern: &str) -> Option<usize> {
+    text.to_ascii_lowercase().find(&pattern.to_ascii_lowercase())
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{tokenize, Attribute, Markup, MarkupRender, Token};
+    use crate::shell::Render;
+    use crate::theme::default::DefaultTheme;
+    use crate::theme::Theme;
+
+    fn render(text: &str, markup: Markup, compact: bool) -> String {
+        let theme = DefaultTheme::new().response();
+        let mut out = Vec::new();
+        MarkupRender::new(text, markup, compact, true, theme.as_markup(), false).write(&mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn content_types() {
+        assert_eq!(Markup::from_content_type("text/xml; charset=utf-8"), Some(Markup::Xml));
+        assert_eq!(Markup::from_content_type("application/xml"), Some(Markup::Xml));
+        assert_eq!(Markup::from_content_type("application/soap+xml"), Some(Markup::Xml));
+        assert_eq!(Markup::from_content_type("Text/HTML"), Some(Markup::Html));
+        assert_eq!(Markup::from_content_type("application/json"), None);
+        assert_eq!(Markup::from_content_type("text/plain"), None);
+    }
+
+    #[test]
+    fn tokens() {
+        assert_eq!(
+            tokenize(r#"<?xml version="1.0"?><a x="1" y='2' z=3 checked/>text</a>"#, false),
+            vec![
+                Token::Comment(r#"<?xml version="1.0"?>"#),
+                Token::Open {
+                    name: "a",
+                    attributes: vec![
+                        Attribute { name: "x", value: Some(r#""1""#) },
+                        Attribute { name: "y", value: Some("'2'") },
+                        Attribute { name: "z", value: Some("3") },
+                        Attribute { name: "checked", value: None },
+                    ],
+                    self_closing: true,
+                },
+                Token::Text("text"),
+                Token::Close("a"),
+            ]
+        );
+    }
+
+    #[test]
+    fn indent_xml() {
+        let xml = r#"<?xml version="1.0"?><soap:Envelope xmlns:soap="http://www.w3.org/2003/05/soap-envelope"><soap:Body><m:Price><m:Item>Apple</m:Item><m:Empty/></m:Price></soap:Body></soap:Envelope>"#;
+        assert_eq!(
+            render(xml, Markup::Xml, false),
+            r#"<?xml version="1.0"?>
+<soap:Envelope xmlns:soap="http://www.w3.org/2003/05/soap-envelope">
+  <soap:Body>
+    <m:Price>
+      <m:Item>Apple</m:Item>
+      <m:Empty/>
+    </m:Price>
+  </soap:Body>
+</soap:Envelope>"#
+        );
+    }
+
+    #[test]
+    fn compact_xml() {
+        let xml = "<a>\n  <b> 1 </b>\n  <!-- c -->\n</a>\n";
+        assert_eq!(render(xml, Markup::Xml, true), "<a><b>1</b><!-- c --></a>");
+    }
+
+    #[test]
+    fn indent_non_ascii_whitespaces() {
+        let xml = "<a>\n  b\n \u{a0}c\n</a>";
+        assert_eq!(render(xml, Markup::Xml, false), "<a>\n   b\n  \u{a0}c\n</a>");
+    }
+
+    #[test]
+    fn indent_html() {
+        let html = "<!DOCTYPE html><html><head><meta charset=utf-8><script>\n    if (a < b) {\n      run();\n    }\n</script></head><body><p>1 < 2<br>ok</p></body></html>";
+        assert_eq!(
+            render(html, Markup::Html, false),
+            r#"<!DOCTYPE html>
+<html>
+  <head>
+    <meta charset=utf-8>
+    <script>
+      if (a < b) {
+        run();
+      }
+    </script>
+  </head>
+  <body>
+    <p>
+      1 < 2
+      <br>
+      ok
+    </p>
+  </body>
+</html>"#
+        );
+    }
+}
@@ -0,0 +1,28 @@
+use super::Render;
+use std::{
+    fmt::Display,
+    io::{Result, Write},
+};
+
+pub struct MessageRender<T> {
+    message: T,
+}
+
+impl<T: Display> MessageRender<T> {
+    pub fn new(message: T) -> Self {
+        Self { message }
+    }
+}
+
+impl<T: Display> Render for MessageRender<T> {
+    #[inline]
+    fn is_style_active(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn write<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writeln!(writer, "{}", self.message)?;
+        Ok(())
+    }
+}
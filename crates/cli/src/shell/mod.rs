@@ -1,36 +1,51 @@
+pub(crate) mod color;
 pub(crate) mod error;
 pub(crate) mod form;
+pub(crate) mod hexdump;
 pub(crate) mod json;
+pub(crate) mod markup;
+pub(crate) mod message;
 pub mod os;
+pub(crate) mod pager;
+pub(crate) mod quote;
 pub(crate) mod stream;
+pub(crate) mod table;
+pub(crate) mod toml;
+pub(crate) mod warning;
+pub(crate) mod wrap;
+pub(crate) mod yaml;
 
 use self::os::OsDirs;
+use self::pager::Pager;
 use crate::theme::style::{Color, Style};
 use ansi_term::Color as AnsiTermColor;
 use ansi_term::Style as AnsiTermStyle;
 use std::io::{Result, Write};
 
-#[inline]
-pub fn enable_colors() -> bool {
-    #[cfg(windows)]
-    return ansi_term::enable_ansi_support().is_ok();
-    #[cfg(not(windows))]
-    true
-}
-
 pub struct Shell<'a, OD, O, E> {
     os_dirs: &'a OD,
     out: O,
     err: E,
+    err_colors: bool,
+    pager: Option<Pager>,
 }
 
 impl<'a, OD: OsDirs, O: Write, E: Write> Shell<'a, OD, O, E> {
     pub fn new(os_dirs: &'a OD, out: O, err: E) -> Self {
-        Self { os_dirs, out, err }
+        Self {
+            os_dirs,
+            out,
+            err,
+            err_colors: color::enabled(stream::is_stderr()),
+            pager: None,
+        }
     }
 
     pub fn out<R: Render>(&mut self, render: R) -> Result<()> {
-        render.write(&mut self.out)?;
+        match self.pager.as_mut() {
+            Some(pager) => render.write(pager.writer())?,
+            None => render.write(&mut self.out)?,
+        }
         Ok(())
     }
     pub fn err<R: Render>(&mut self, render: R) -> Result<()> {
@@ -38,13 +53,27 @@ impl<'a, OD: OsDirs, O: Write, E: Write> Shell<'a, OD, O, E> {
         Ok(())
     }
 
+    // The output is held until show_pager() is called
+    pub fn enable_pager(&mut self, always: bool) {
+        self.pager = Some(Pager::new(always));
+    }
+    pub fn show_pager(&mut self) -> Result<()> {
+        match self.pager.take() {
+            Some(pager) => pager.show(&mut self.out),
+            None => Ok(()),
+        }
+    }
+
     pub fn os_dirs(&self) -> &OD {
         self.os_dirs
     }
 
-    #[inline]
-    pub fn enable_colors(&self) -> bool {
-        enable_colors()
+    // The colours of the errors and warnings
+    pub fn err_colors(&self) -> bool {
+        self.err_colors
+    }
+    pub fn disable_err_colors(&mut self) {
+        self.err_colors = false;
     }
 
     // pub fn flush(&mut self) -> Result<()> {
@@ -65,14 +94,7 @@ pub trait Render {
 
     fn write_with_style<W: Write>(&self, writer: &mut W, buf: &[u8], style: &Style) -> Result<()> {
         if self.is_style_active() {
-            AnsiTermStyle {
-                is_bold: style.is_bold,
-                is_dimmed: style.is_dimmed,
-                foreground: to_ansi_term_color(style.forecolor),
-                ..AnsiTermStyle::default()
-            }
-            .paint(buf)
-            .write_to(writer)?;
+            AnsiTermStyle::from(style).paint(buf).write_to(writer)?;
         } else {
             writer.write_all(buf)?;
         }
@@ -81,9 +103,15 @@ pub trait Render {
     }
 }
 
-#[inline]
-fn to_ansi_term_color(color: Option<Color>) -> Option<AnsiTermColor> {
-    color.map(|color| color.into())
+impl From<&Style> for AnsiTermStyle {
+    fn from(style: &Style) -> AnsiTermStyle {
+        AnsiTermStyle {
+            is_bold: style.is_bold,
+            is_dimmed: style.is_dimmed,
+            foreground: style.forecolor.map(|color| color.into()),
+            ..AnsiTermStyle::default()
+        }
+    }
 }
 
 impl From<Color> for AnsiTermColor {
@@ -97,6 +125,8 @@ impl From<Color> for AnsiTermColor {
             Color::Purple => AnsiTermColor::Purple,
             Color::Cyan => AnsiTermColor::Cyan,
             Color::White => AnsiTermColor::White,
+            Color::Fixed(index) => AnsiTermColor::Fixed(index),
+            Color::Rgb(red, green, blue) => AnsiTermColor::RGB(red, green, blue),
         }
     }
 }
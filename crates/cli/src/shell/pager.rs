@@ -0,0 +1,96 @@
+use std::env;
+use std::io::{ErrorKind, Result, Write};
+use std::process::{Command, Stdio};
+
+const DEFAULT_PAGER: &str = "less -R";
+
+// The output is kept in memory, then shown in a pager if it doesn't fit in the terminal
+pub struct Pager {
+    buffer: Vec<u8>,
+    always: bool,
+}
+
+impl Pager {
+    pub fn new(always: bool) -> Self {
+        Self { buffer: Vec::new(), always }
+    }
+
+    pub fn writer(&mut self) -> &mut Vec<u8> {
+        &mut self.buffer
+    }
+
+    // Falls back to the standard output if the pager can't be started
+    pub fn show<O: Write>(self, out: &mut O) -> Result<()> {
+        let fits = termsize::get().map_or(true, |size| line_count(&self.buffer) < size.rows as usize);
+        if fits && !self.always {
+            return out.write_all(&self.buffer);
+        }
+        match command(env::var("PAGER").ok()).map(|args| spawn(&args, &self.buffer)) {
+            Some(Ok(())) => Ok(()),
+            _ => out.write_all(&self.buffer),
+        }
+    }
+}
+
+fn spawn(args: &[String], content: &[u8]) -> Result<()> {
+    let mut command = Command::new(&args[0]);
+    command.args(&args[1..]).stdin(Stdio::piped());
+    // Keeps the colours if $PAGER is less without -R
+    if env::var_os("LESS").is_none() {
+        command.env("LESS", "R");
+    }
+    let mut child = command.spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        match stdin.write_all(content) {
+            // The pager has been closed before the end of the output
+            Err(err) if err.kind() == ErrorKind::BrokenPipe => {}
+            result => result?,
+        }
+    }
+    child.wait()?;
+    Ok(())
+}
+
+// An empty $PAGER means no pager
+fn command(pager: Option<String>) -> Option<Vec<String>> {
+    let pager = pager.unwrap_or_else(|| DEFAULT_PAGER.to_string());
+    let args: Vec<String> = pager.split_whitespace().map(String::from).collect();
+    if args.is_empty() {
+        None
+    } else {
+        Some(args)
+    }
+}
+
+fn line_count(buffer: &[u8]) -> usize {
+    let lines = buffer.iter().filter(|byte| **byte == b'\n').count();
+    if buffer.last().is_some_and(|byte| *byte != b'\n') {
+        lines + 1
+    } else {
+        lines
+    }
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{command, line_count};
+
+    #[test]
+    fn pager_command() {
+        assert_eq!(command(None), Some(vec!["less".to_string(), "-R".to_string()]));
+        assert_eq!(command(Some("more".into())), Some(vec!["more".to_string()]));
+        assert_eq!(command(Some(" bat  --paging=always ".into())), Some(vec!["bat".to_string(), "--paging=always".to_string()]));
+        assert_eq!(command(Some("".into())), None);
+    }
+
+    #[test]
+    fn lines() {
+        assert_eq!(line_count(b""), 0);
+        assert_eq!(line_count(b"a"), 1);
+        assert_eq!(line_count(b"a\n"), 1);
+        assert_eq!(line_count(b"a\nb"), 2);
+        assert_eq!(line_count(b"a\n\nb\n"), 3);
+    }
+}
@@ -0,0 +1,52 @@
+use std::borrow::Cow;
+
+pub fn quote(arg: &str) -> Cow<'_, str> {
+    if !arg.is_empty() && arg.chars().all(is_safe_char) {
+        Cow::Borrowed(arg)
+    } else {
+        Cow::Owned(format!("'{}'", arg.replace('\'', "'\\''")))
+    }
+}
+
+pub fn join<S: AsRef<str>>(args: &[S]) -> String {
+    args.iter().map(|arg| quote(arg.as_ref())).collect::<Vec<_>>().join(" ")
+}
+
+#[inline]
+fn is_safe_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || ['-', '_', '.', '/', ':', '@', '%', '+', ',', '='].contains(&c)
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{join, quote};
+
+    #[test]
+    fn no_quotes_if_safe() {
+        assert_eq!(quote("http://test.com/a/b.html"), "http://test.com/a/b.html");
+        assert_eq!(quote("-X"), "-X");
+        assert_eq!(quote("key=value"), "key=value");
+    }
+
+    #[test]
+    fn quotes_if_unsafe() {
+        assert_eq!(quote(""), "''");
+        assert_eq!(quote("http://test.com/?a=1&b=2"), "'http://test.com/?a=1&b=2'");
+        assert_eq!(quote("accept: application/json"), "'accept: application/json'");
+        assert_eq!(quote(r#"{"key":"$value"}"#), r#"'{"key":"$value"}'"#);
+    }
+
+    #[test]
+    fn escape_single_quotes() {
+        assert_eq!(quote("it's"), r#"'it'\''s'"#);
+        assert_eq!(quote("''"), r#"''\'''\'''"#);
+    }
+
+    #[test]
+    fn join_args() {
+        assert_eq!(join(&["GET", "test.com", "X-Key:a b"]), "GET test.com 'X-Key:a b'");
+        assert_eq!(join::<&str>(&[]), "");
+    }
+}
@@ -4,6 +4,10 @@ pub fn is_stdout() -> bool {
     is(Stream::Stdout)
 }
 
+pub fn is_stderr() -> bool {
+    is(Stream::Stderr)
+}
+
 pub fn is_stdin() -> bool {
     is(Stream::Stdin)
 }
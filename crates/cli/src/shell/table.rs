@@ -0,0 +1,189 @@
+use super::Render;
+use crate::theme::DataTheme;
+use serde_json::{Map, Value};
+use std::io::{Result, Write};
+
+const SEPARATOR: &str = "  ";
+const ELLIPSIS: char = '…';
+const MIN_COLUMN_WIDTH: usize = 4;
+
+pub struct TableRender<'a> {
+    rows: Vec<&'a Map<String, Value>>,
+    columns: Vec<String>,
+    max_width: Option<usize>,
+    theme: &'a dyn DataTheme,
+    style_enabled: bool,
+}
+
+impl<'a> TableRender<'a> {
+    // Returns None if the values aren't all objects
+    pub fn new(values: &[&'a Value], columns: &[String], max_width: Option<usize>, theme: &'a dyn DataTheme, style_enabled: bool) -> Option<Self> {
+        let rows = match values {
+            [Value::Array(values)] => values.iter().map(Value::as_object).collect::<Option<Vec<_>>>()?,
+            values => values.iter().map(|value| value.as_object()).collect::<Option<Vec<_>>>()?,
+        };
+        if rows.is_empty() {
+            return None;
+        }
+        let columns = if columns.is_empty() { all_columns(&rows) } else { columns.to_vec() };
+        Some(Self {
+            rows,
+            columns,
+            max_width,
+            theme,
+            style_enabled,
+        })
+    }
+}
+
+impl<'a> Render for TableRender<'a> {
+    #[inline]
+    fn is_style_active(&self) -> bool {
+        self.style_enabled
+    }
+
+    fn write<W: Write>(&self, writer: &mut W) -> Result<()> {
+        let cells: Vec<Vec<(String, Option<&Value>)>> = self
+            .rows
+            .iter()
+            .map(|row| self.columns.iter().map(|column| (cell(row.get(column)), row.get(column))).collect())
+            .collect();
+        let widths = self.widths(&cells);
+
+        let headers: Vec<(String, Option<&Value>)> = self.columns.iter().map(|column| (column.clone(), None)).collect();
+        self.write_row(writer, &headers, &widths, true)?;
+        self.write_newline(writer)?;
+        let dashes: Vec<(String, Option<&Value>)> = widths.iter().map(|width| ("-".repeat(*width), None)).collect();
+        self.write_row(writer, &dashes, &widths, false)?;
+        for row in cells.iter() {
+            self.write_newline(writer)?;
+            self.write_row(writer, row, &widths, false)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> TableRender<'a> {
+    fn write_row<W: Write>(&self, writer: &mut W, cells: &[(String, Option<&Value>)], widths: &[usize], header: bool) -> Result<()> {
+        for (index, ((text, value), width)) in cells.iter().zip(widths.iter()).enumerate() {
+            let text = truncate(text, *width);
+            let style = match value {
+                _ if header => self.theme.data_key(),
+                Some(Value::String(_)) => self.theme.data_string(),
+                Some(Value::Number(_)) => self.theme.data_number(),
+                Some(Value::Bool(_)) => self.theme.data_bool(),
+                Some(Value::Null) => self.theme.data_null(),
+                _ => Default::default(),
+            };
+            self.write_with_style(writer, text.as_bytes(), &style)?;
+            // No trailing spaces after the last column
+            if index + 1 < cells.len() {
+                let padding = width - text.chars().count();
+                writer.write_all(" ".repeat(padding).as_bytes())?;
+                writer.write_all(SEPARATOR.as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    // The widest columns are shrunk until the table fits
+    fn widths(&self, cells: &[Vec<(String, Option<&Value>)>]) -> Vec<usize> {
+        let mut widths: Vec<usize> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| cells.iter().map(|row| row[index].0.chars().count()).chain([column.chars().count()]).max().unwrap_or(0))
+            .collect();
+        if let Some(max_width) = self.max_width {
+            let separators = SEPARATOR.len() * widths.len().saturating_sub(1);
+            while widths.iter().sum::<usize>() + separators > max_width {
+                let (widest, width) = widths
+                    .iter()
+                    .enumerate()
+                    .max_by_key(|(_, width)| **width)
+                    .map(|(index, width)| (index, *width))
+                    .unwrap_or_default();
+                if width <= MIN_COLUMN_WIDTH {
+                    break;
+                }
+                widths[widest] -= 1;
+            }
+        }
+        widths
+    }
+}
+
+// The columns in the order they appear
+fn all_columns(rows: &[&Map<String, Value>]) -> Vec<String> {
+    let mut columns: Vec<String> = Vec::new();
+    for key in rows.iter().flat_map(|row| row.keys()) {
+        if !columns.contains(key) {
+            columns.push(key.clone());
+        }
+    }
+    columns
+}
+
+fn cell(value: Option<&Value>) -> String {
+    let text = match value {
+        None => String::new(),
+        Some(Value::String(text)) => text.clone(),
+        Some(value) => value.to_string(),
+    };
+    text.replace(|c: char| c.is_control(), " ")
+}
+
+fn truncate(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        text.to_string()
+    } else {
+        let mut truncated: String = text.chars().take(width.saturating_sub(1)).collect();
+        truncated.push(ELLIPSIS);
+        truncated
+    }
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::TableRender;
+    use crate::shell::Render;
+    use crate::theme::default::DefaultTheme;
+    use crate::theme::Theme;
+    use serde_json::{json, Value};
+
+    fn render(values: &[&Value], columns: &[&str], max_width: Option<usize>) -> Option<String> {
+        let theme = DefaultTheme::new().response();
+        let columns: Vec<String> = columns.iter().map(|column| column.to_string()).collect();
+        let table = TableRender::new(values, &columns, max_width, theme.as_data(), false)?;
+        let mut out = Vec::new();
+        table.write(&mut out).unwrap();
+        Some(String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn not_a_table() {
+        assert_eq!(render(&[&json!([1, 2])], &[], None), None);
+        assert_eq!(render(&[&json!([])], &[], None), None);
+        assert_eq!(render(&[&json!({"a": 1}), &json!("b")], &[], None), None);
+    }
+
+    #[test]
+    fn all_columns() {
+        let value = json!([{"id": 1, "name": "alice"}, {"id": 22, "status": null, "name": "bob"}]);
+        assert_eq!(render(&[&value], &[], None).unwrap(), "id  name   status\n--  -----  ------\n1   alice  \n22  bob    null");
+    }
+
+    #[test]
+    fn selected_columns() {
+        let (first, second) = (json!({"id": 1, "name": "alice", "tags": ["a"]}), json!({"id": 2}));
+        assert_eq!(render(&[&first, &second], &["tags", "id"], None).unwrap(), "tags   id\n-----  --\n[\"a\"]  1\n       2");
+    }
+
+    #[test]
+    fn truncated() {
+        let value = json!([{"id": 1, "description": "a very long description"}]);
+        assert_eq!(render(&[&value], &[], Some(20)).unwrap(), "id  description\n--  ----------------\n1   a very long des…");
+    }
+}
@@ -0,0 +1,202 @@
+use super::Render;
+use crate::theme::DataTheme;
+use serde_json::{Map, Value};
+use std::io::{Error, ErrorKind, Result, Write};
+
+pub struct TomlRender<'a> {
+    value: &'a Value,
+    theme: &'a dyn DataTheme,
+    style_enabled: bool,
+}
+
+impl<'a> TomlRender<'a> {
+    pub fn new(value: &'a Value, theme: &'a dyn DataTheme, style_enabled: bool) -> Self {
+        Self { value, theme, style_enabled }
+    }
+}
+
+impl<'a> Render for TomlRender<'a> {
+    #[inline]
+    fn is_style_active(&self) -> bool {
+        self.style_enabled
+    }
+
+    #[inline]
+    fn write<W: Write>(&self, writer: &mut W) -> Result<()> {
+        match self.value {
+            Value::Object(map) => {
+                let mut first_line = true;
+                self.write_table(writer, map, &mut Vec::new(), &mut first_line)
+            }
+            _ => Err(Error::new(
+                ErrorKind::InvalidData,
+                "a TOML document must be a JSON object, try the --output-format=yaml option",
+            )),
+        }
+    }
+}
+
+impl<'a> TomlRender<'a> {
+    // The key/value pairs first, then the sub-tables and the arrays of tables (TOML has no null, they're skipped)
+    fn write_table<W: Write>(&self, writer: &mut W, map: &Map<String, Value>, path: &mut Vec<String>, first_line: &mut bool) -> Result<()> {
+        for (key, value) in map.iter().filter(|(_, value)| is_key_value(value)) {
+            self.start_line(writer, first_line)?;
+            self.write_with_style(writer, bare_or_quoted(key).as_bytes(), &self.theme.data_key())?;
+            writer.write_all(b" = ")?;
+            self.write_inline(writer, value)?;
+        }
+        for (key, value) in map.iter() {
+            path.push(bare_or_quoted(key));
+            match value {
+                Value::Object(table) if is_table(value) => {
+                    // The header of a table that only contains tables is implicit
+                    if table.values().any(is_key_value) || !table.values().any(|value| is_table(value) || is_array_of_tables(value)) {
+                        self.write_header(writer, path, false, first_line)?;
+                    }
+                    self.write_table(writer, table, path, first_line)?;
+                }
+                Value::Array(tables) if is_array_of_tables(value) => {
+                    for table in tables.iter().filter_map(Value::as_object) {
+                        self.write_header(writer, path, true, first_line)?;
+                        self.write_table(writer, table, path, first_line)?;
+                    }
+                }
+                _ => {}
+            }
+            path.pop();
+        }
+        Ok(())
+    }
+
+    fn write_header<W: Write>(&self, writer: &mut W, path: &[String], array: bool, first_line: &mut bool) -> Result<()> {
+        if !*first_line {
+            self.write_newline(writer)?;
+        }
+        self.start_line(writer, first_line)?;
+        let header = if array { format!("[[{}]]", path.join(".")) } else { format!("[{}]", path.join(".")) };
+        self.write_with_style(writer, header.as_bytes(), &self.theme.data_key())
+    }
+
+    fn write_inline<W: Write>(&self, writer: &mut W, value: &Value) -> Result<()> {
+        let theme = self.theme;
+        match value {
+            Value::Null => Ok(()),
+            Value::Bool(value) => self.write_with_style(writer, value.to_string().as_bytes(), &theme.data_bool()),
+            Value::Number(value) => self.write_with_style(writer, value.to_string().as_bytes(), &theme.data_number()),
+            // A JSON string is a valid TOML basic string
+            Value::String(value) => self.write_with_style(writer, serde_json::to_string(value)?.as_bytes(), &theme.data_string()),
+            Value::Array(values) => {
+                writer.write_all(b"[")?;
+                for (index, value) in values.iter().filter(|value| !value.is_null()).enumerate() {
+                    if index > 0 {
+                        writer.write_all(b", ")?;
+                    }
+                    self.write_inline(writer, value)?;
+                }
+                writer.write_all(b"]")
+            }
+            Value::Object(map) => {
+                writer.write_all(b"{")?;
+                for (index, (key, value)) in map.iter().filter(|(_, value)| !value.is_null()).enumerate() {
+                    writer.write_all(if index > 0 { b", " } else { b" " })?;
+                    self.write_with_style(writer, bare_or_quoted(key).as_bytes(), &theme.data_key())?;
+                    writer.write_all(b" = ")?;
+                    self.write_inline(writer, value)?;
+                }
+                writer.write_all(if map.is_empty() { b"}" } else { b" }" })
+            }
+        }
+    }
+
+    fn start_line<W: Write>(&self, writer: &mut W, first_line: &mut bool) -> Result<()> {
+        if !*first_line {
+            self.write_newline(writer)?;
+        }
+        *first_line = false;
+        Ok(())
+    }
+}
+
+fn is_key_value(value: &Value) -> bool {
+    !value.is_null() && !is_table(value) && !is_array_of_tables(value)
+}
+
+fn is_table(value: &Value) -> bool {
+    matches!(value, Value::Object(map) if !map.is_empty())
+}
+
+fn is_array_of_tables(value: &Value) -> bool {
+    matches!(value, Value::Array(values) if !values.is_empty() && values.iter().all(Value::is_object))
+}
+
+fn bare_or_quoted(key: &str) -> String {
+    if !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+        key.to_string()
+    } else {
+        serde_json::to_string(key).unwrap_or_default()
+    }
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::TomlRender;
+    use crate::shell::Render;
+    use crate::theme::default::DefaultTheme;
+    use crate::theme::Theme;
+    use serde_json::{json, Value};
+
+    fn render(value: &Value) -> std::io::Result<String> {
+        let theme = DefaultTheme::new().response();
+        let mut out = Vec::new();
+        TomlRender::new(value, theme.as_data(), false).write(&mut out)?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn not_an_object() {
+        assert!(render(&json!([1, 2])).is_err());
+        assert!(render(&json!("text")).is_err());
+    }
+
+    #[test]
+    fn tables() {
+        let value = json!({
+            "name": "rh",
+            "owner": {"login": "twigly", "id": 1, "links": {"home": "https://github.com/twigly"}},
+            "data": {"user": {"name": "Alice"}},
+            "tags": ["cli", "http"],
+            "a key": null,
+            "items": [{"id": 1, "pos": {"x": 1}}, {"id": 2}],
+            "mixed": [1, {"a": 1}],
+            "empty": {}
+        });
+        assert_eq!(
+            render(&value).unwrap(),
+            r#"name = "rh"
+tags = ["cli", "http"]
+mixed = [1, { a = 1 }]
+empty = {}
+
+[owner]
+login = "twigly"
+id = 1
+
+[owner.links]
+home = "https://github.com/twigly"
+
+[data.user]
+name = "Alice"
+
+[[items]]
+id = 1
+
+[items.pos]
+x = 1
+
+[[items]]
+id = 2"#
+        );
+    }
+}
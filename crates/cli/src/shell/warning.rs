@@ -0,0 +1,31 @@
+use super::Render;
+use crate::theme::style::Color;
+use std::{
+    fmt::Display,
+    io::{Result, Write},
+};
+
+pub struct WarningRender<T> {
+    message: T,
+    style_enabled: bool,
+}
+
+impl<T: Display> WarningRender<T> {
+    pub fn new(message: T, style_enabled: bool) -> Self {
+        Self { message, style_enabled }
+    }
+}
+
+impl<T: Display> Render for WarningRender<T> {
+    #[inline]
+    fn is_style_active(&self) -> bool {
+        self.style_enabled
+    }
+
+    #[inline]
+    fn write<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.write_with_style(writer, "Warning: ".as_bytes(), &Color::Yellow.bold())?;
+        writeln!(writer, "{}", self.message)?;
+        Ok(())
+    }
+}
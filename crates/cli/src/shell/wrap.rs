@@ -0,0 +1,38 @@
+// Below this width, the wrapped text would be harder to read than the text wrapped by the terminal
+pub const MIN_WIDTH: usize = 16;
+
+// Splits a text in lines of at most `width` characters (the text isn't split if the width is too small)
+pub fn wrap(text: &str, width: usize) -> Vec<&str> {
+    if width < MIN_WIDTH {
+        return vec![text];
+    }
+    let mut lines = Vec::new();
+    let mut rest = text;
+    while let Some((index, _)) = rest.char_indices().nth(width) {
+        let (line, next) = rest.split_at(index);
+        lines.push(line);
+        rest = next;
+    }
+    lines.push(rest);
+    lines
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::wrap;
+
+    #[test]
+    fn wrapped() {
+        assert_eq!(wrap("", 16), vec![""]);
+        assert_eq!(wrap("0123456789abcdef", 16), vec!["0123456789abcdef"]);
+        assert_eq!(wrap("0123456789abcdef0123456789abcdef01", 16), vec!["0123456789abcdef", "0123456789abcdef", "01"]);
+        assert_eq!(wrap("éééééééééééééééééé", 16), vec!["éééééééééééééééé", "éé"]);
+    }
+
+    #[test]
+    fn too_narrow() {
+        assert_eq!(wrap("0123456789abcdef0123456789abcdef", 15), vec!["0123456789abcdef0123456789abcdef"]);
+    }
+}
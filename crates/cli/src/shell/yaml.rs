@@ -0,0 +1,182 @@
+use super::Render;
+use crate::theme::DataTheme;
+use serde_json::{Map, Value};
+use std::io::{Result, Write};
+
+const INDENT: usize = 2;
+
+pub struct YamlRender<'a> {
+    value: &'a Value,
+    theme: &'a dyn DataTheme,
+    style_enabled: bool,
+}
+
+impl<'a> YamlRender<'a> {
+    pub fn new(value: &'a Value, theme: &'a dyn DataTheme, style_enabled: bool) -> Self {
+        Self { value, theme, style_enabled }
+    }
+}
+
+impl<'a> Render for YamlRender<'a> {
+    #[inline]
+    fn is_style_active(&self) -> bool {
+        self.style_enabled
+    }
+
+    #[inline]
+    fn write<W: Write>(&self, writer: &mut W) -> Result<()> {
+        match self.value {
+            Value::Object(map) if !map.is_empty() => self.write_mapping(writer, map, 0, true),
+            Value::Array(values) if !values.is_empty() => self.write_sequence(writer, values, 0, true),
+            value => self.write_scalar(writer, value),
+        }
+    }
+}
+
+impl<'a> YamlRender<'a> {
+    // The first line may continue the current line (e.g. after "- ")
+    fn write_mapping<W: Write>(&self, writer: &mut W, map: &Map<String, Value>, indent: usize, inline: bool) -> Result<()> {
+        for (index, (key, value)) in map.iter().enumerate() {
+            if index > 0 || !inline {
+                self.write_newline(writer)?;
+                writer.write_all(" ".repeat(indent).as_bytes())?;
+            }
+            self.write_with_style(writer, quote(key).as_bytes(), &self.theme.data_key())?;
+            writer.write_all(b":")?;
+            self.write_child(writer, value, indent + INDENT)?;
+        }
+        Ok(())
+    }
+
+    fn write_sequence<W: Write>(&self, writer: &mut W, values: &[Value], indent: usize, inline: bool) -> Result<()> {
+        for (index, value) in values.iter().enumerate() {
+            if index > 0 || !inline {
+                self.write_newline(writer)?;
+                writer.write_all(" ".repeat(indent).as_bytes())?;
+            }
+            writer.write_all(b"- ")?;
+            match value {
+                Value::Object(map) if !map.is_empty() => self.write_mapping(writer, map, indent + INDENT, true)?,
+                Value::Array(values) if !values.is_empty() => self.write_sequence(writer, values, indent + INDENT, true)?,
+                value => self.write_scalar(writer, value)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn write_child<W: Write>(&self, writer: &mut W, value: &Value, indent: usize) -> Result<()> {
+        match value {
+            Value::Object(map) if !map.is_empty() => self.write_mapping(writer, map, indent, false),
+            Value::Array(values) if !values.is_empty() => self.write_sequence(writer, values, indent, false),
+            value => {
+                writer.write_all(b" ")?;
+                self.write_scalar(writer, value)
+            }
+        }
+    }
+
+    fn write_scalar<W: Write>(&self, writer: &mut W, value: &Value) -> Result<()> {
+        let theme = self.theme;
+        match value {
+            Value::Null => self.write_with_style(writer, b"null", &theme.data_null()),
+            Value::Bool(value) => self.write_with_style(writer, value.to_string().as_bytes(), &theme.data_bool()),
+            Value::Number(value) => self.write_with_style(writer, value.to_string().as_bytes(), &theme.data_number()),
+            Value::String(value) => self.write_with_style(writer, quote(value).as_bytes(), &theme.data_string()),
+            Value::Object(_) => writer.write_all(b"{}"),
+            Value::Array(_) => writer.write_all(b"[]"),
+        }
+    }
+}
+
+// A JSON string is a valid YAML double-quoted string
+fn quote(text: &str) -> String {
+    if is_plain(text) {
+        text.to_string()
+    } else {
+        serde_json::to_string(text).unwrap_or_default()
+    }
+}
+
+// Tells if a string can be written without quotes and still be read as a string
+fn is_plain(text: &str) -> bool {
+    const RESERVED: [&str; 12] = ["true", "false", "yes", "no", "on", "off", "y", "n", "null", "~", ".nan", ".inf"];
+    let first = match text.chars().next() {
+        Some(first) => first,
+        None => return false,
+    };
+    !"-?:,[]{}#&*!|>'\"%@`".contains(first)
+        && !first.is_whitespace()
+        && !text.ends_with(char::is_whitespace)
+        && !text.ends_with(':')
+        && !text.contains(": ")
+        && !text.contains(" #")
+        && !text.chars().any(char::is_control)
+        && !RESERVED.iter().any(|reserved| reserved.eq_ignore_ascii_case(text))
+        && text.parse::<f64>().is_err()
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::YamlRender;
+    use crate::shell::Render;
+    use crate::theme::default::DefaultTheme;
+    use crate::theme::Theme;
+    use serde_json::{json, Value};
+
+    fn render(value: &Value) -> String {
+        let theme = DefaultTheme::new().response();
+        let mut out = Vec::new();
+        YamlRender::new(value, theme.as_data(), false).write(&mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn scalars() {
+        assert_eq!(render(&json!(null)), "null");
+        assert_eq!(render(&json!(1.5)), "1.5");
+        assert_eq!(render(&json!("text")), "text");
+        assert_eq!(render(&json!({})), "{}");
+    }
+
+    #[test]
+    fn quoted_strings() {
+        assert_eq!(
+            render(&json!(["yes", "12", "", " a", "a: b", "#a", "multi\nline", "- a", "ok"])),
+            "- \"yes\"\n- \"12\"\n- \"\"\n- \" a\"\n- \"a: b\"\n- \"#a\"\n- \"multi\\nline\"\n- \"- a\"\n- ok"
+        );
+    }
+
+    #[test]
+    fn nested() {
+        let value = json!({
+            "name": "rh",
+            "tags": ["cli", "http"],
+            "owner": {"login": "twigly", "id": 1},
+            "items": [{"id": 1, "ok": true}, {"id": 2, "sub": [1, [2, 3]]}],
+            "empty": [],
+            "none": null
+        });
+        assert_eq!(
+            render(&value),
+            r#"name: rh
+tags:
+  - cli
+  - http
+owner:
+  login: twigly
+  id: 1
+items:
+  - id: 1
+    ok: true
+  - id: 2
+    sub:
+      - 1
+      - - 2
+        - 3
+empty: []
+none: null"#
+        );
+    }
+}
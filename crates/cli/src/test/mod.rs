@@ -1,4 +1,6 @@
+#[cfg(feature = "alias")]
 pub(crate) mod alias;
+#[cfg(feature = "alias")]
 pub(crate) mod os;
 
 // #[macro_export]
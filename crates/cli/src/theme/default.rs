@@ -1,6 +1,6 @@
 use super::{
     style::{Color, Style},
-    DirectionTheme, HeaderTheme, RequestTheme, ResponseTheme, Theme,
+    DataTheme, DirectionTheme, HeaderTheme, MarkupTheme, RequestTheme, ResponseTheme, Theme,
 };
 
 #[cfg_attr(test, derive(Debug))]
@@ -18,6 +18,9 @@ impl RequestTheme for DefaultRequestTheme {
     fn as_direction(&self) -> &dyn DirectionTheme {
         self
     }
+    fn as_data(&self) -> &dyn DataTheme {
+        self
+    }
     fn primary(&self) -> Style {
         Color::Purple.normal()
     }
@@ -44,6 +47,27 @@ impl DirectionTheme for DefaultRequestTheme {
         crate::ifelse!(standard, self.primary(), self.secondary())
     }
 }
+// The request and the response data have the same colours
+impl DataTheme for DefaultRequestTheme {
+    fn data_key(&self) -> Style {
+        DefaultReponseTheme {}.data_key()
+    }
+    fn data_string(&self) -> Style {
+        DefaultReponseTheme {}.data_string()
+    }
+    fn data_number(&self) -> Style {
+        DefaultReponseTheme {}.data_number()
+    }
+    fn data_bool(&self) -> Style {
+        DefaultReponseTheme {}.data_bool()
+    }
+    fn data_null(&self) -> Style {
+        DefaultReponseTheme {}.data_null()
+    }
+    fn data_bracket(&self) -> Style {
+        DefaultReponseTheme {}.data_bracket()
+    }
+}
 
 impl ResponseTheme for DefaultReponseTheme {
     fn as_header(&self) -> &dyn HeaderTheme {
@@ -52,6 +76,12 @@ impl ResponseTheme for DefaultReponseTheme {
     fn as_direction(&self) -> &dyn DirectionTheme {
         self
     }
+    fn as_markup(&self) -> &dyn MarkupTheme {
+        self
+    }
+    fn as_data(&self) -> &dyn DataTheme {
+        self
+    }
     fn primary(&self) -> Style {
         Color::Green.normal()
     }
@@ -64,6 +94,33 @@ impl ResponseTheme for DefaultReponseTheme {
     fn status(&self) -> Style {
         Color::Green.bold_newline()
     }
+    fn status_success(&self) -> Style {
+        Color::Green.bold_newline()
+    }
+    fn status_redirection(&self) -> Style {
+        Color::Cyan.bold_newline()
+    }
+    fn status_client_error(&self) -> Style {
+        Color::Yellow.bold_newline()
+    }
+    fn status_server_error(&self) -> Style {
+        Color::Red.bold_newline()
+    }
+    fn timing_label(&self) -> Style {
+        self.secondary()
+    }
+    fn timing_value(&self) -> Style {
+        Color::Yellow.normal()
+    }
+    fn tls_label(&self) -> Style {
+        self.secondary()
+    }
+    fn tls_value(&self) -> Style {
+        Style::default()
+    }
+    fn tls_warning(&self) -> Style {
+        Color::Red.bold()
+    }
 }
 impl HeaderTheme for DefaultReponseTheme {
     fn header_name(&self, standard: bool) -> Style {
@@ -78,6 +135,43 @@ impl DirectionTheme for DefaultReponseTheme {
         crate::ifelse!(standard, self.primary(), self.secondary())
     }
 }
+impl DataTheme for DefaultReponseTheme {
+    fn data_key(&self) -> Style {
+        Color::Blue.normal()
+    }
+    fn data_string(&self) -> Style {
+        Color::Green.normal()
+    }
+    fn data_number(&self) -> Style {
+        Color::Purple.normal()
+    }
+    fn data_bool(&self) -> Style {
+        Color::Yellow.normal()
+    }
+    fn data_null(&self) -> Style {
+        Color::Cyan.normal()
+    }
+    fn data_bracket(&self) -> Style {
+        Color::Red.normal()
+    }
+}
+impl MarkupTheme for DefaultReponseTheme {
+    fn markup_tag(&self) -> Style {
+        Color::Blue.normal()
+    }
+    fn markup_attribute(&self) -> Style {
+        Color::Cyan.normal()
+    }
+    fn markup_value(&self) -> Style {
+        Color::Green.normal()
+    }
+    fn markup_comment(&self) -> Style {
+        Style {
+            is_dimmed: true,
+            ..Default::default()
+        }
+    }
+}
 
 impl Theme for DefaultTheme {
     fn request(&self) -> Box<dyn RequestTheme> {
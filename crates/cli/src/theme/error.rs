@@ -0,0 +1,26 @@
+use crate::core::Error as CoreError;
+use std::fmt;
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(PartialEq)]
+pub enum Error {
+    NoConfigDirectory,
+    NotFound(String, String),
+    Invalid(String, String),
+}
+
+impl From<Error> for CoreError {
+    fn from(err: Error) -> CoreError {
+        CoreError::Theme(err)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::NoConfigDirectory => write!(f, "the config directory can't be found"),
+            Error::NotFound(name, path) => write!(f, "'{}' doesn't exist ({})", name, path),
+            Error::Invalid(name, message) => write!(f, "'{}' is invalid, {}", name, message),
+        }
+    }
+}
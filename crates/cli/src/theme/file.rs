@@ -0,0 +1,460 @@
+use super::default::{DefaultReponseTheme, DefaultRequestTheme};
+use super::error::Error;
+use super::style::{Color, Style};
+use super::{DataTheme, DirectionTheme, HeaderTheme, MarkupTheme, RequestTheme, ResponseTheme, Theme};
+use crate::shell::os::OsDirs;
+use std::collections::HashMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::rc::Rc;
+use toml::Value;
+
+const THEME_DIRECTORY: &str = "themes";
+
+const SLOTS: [&str; 31] = [
+    "request.primary",
+    "request.secondary",
+    "request.method",
+    "request.url",
+    "response.primary",
+    "response.secondary",
+    "response.version",
+    "response.status",
+    "response.status_success",
+    "response.status_redirection",
+    "response.status_client_error",
+    "response.status_server_error",
+    "response.timing_label",
+    "response.timing_value",
+    "response.tls_label",
+    "response.tls_value",
+    "response.tls_warning",
+    "json.key",
+    "json.string",
+    "json.number",
+    "json.bool",
+    "json.null",
+    "json.bracket",
+    "markup.tag",
+    "markup.attribute",
+    "markup.value",
+    "markup.comment",
+    "header.name",
+    "header.value",
+    "header.custom_name",
+    "header.custom_value",
+];
+
+type Styles = Rc<HashMap<&'static str, Style>>;
+
+// A theme file only needs the styles to change, the other ones are the default ones
+pub struct FileTheme {
+    styles: Styles,
+}
+pub struct FileRequestTheme {
+    styles: Styles,
+}
+pub struct FileResponseTheme {
+    styles: Styles,
+}
+
+// The themes are in <config directory>/rh/themes/<name>.toml
+pub fn load<OD: OsDirs>(os_dirs: &OD, name: &str) -> Result<FileTheme, Error> {
+    let directory = os_dirs.app_config_directory().ok_or(Error::NoConfigDirectory)?;
+    let path = directory.join(THEME_DIRECTORY).join(format!("{}.toml", name));
+    match fs::read_to_string(&path) {
+        Ok(content) => parse(&content).map_err(|message| Error::Invalid(name.to_string(), message)),
+        Err(err) if err.kind() == ErrorKind::NotFound => Err(Error::NotFound(name.to_string(), path.display().to_string())),
+        Err(err) => Err(Error::Invalid(name.to_string(), err.to_string())),
+    }
+}
+
+fn parse(content: &str) -> Result<FileTheme, String> {
+    let sections: toml::value::Table = toml::from_str(content).map_err(|err| err.to_string())?;
+    let mut styles = HashMap::new();
+    for (section, values) in sections.iter() {
+        let values = values.as_table().ok_or(format!("'{}' must be a table", section))?;
+        for (name, value) in values.iter() {
+            let key = format!("{}.{}", section, name);
+            let slot = SLOTS.iter().find(|slot| **slot == key).ok_or(format!("'{}' is unknown", key))?;
+            styles.insert(*slot, style(value).map_err(|message| format!("{} for '{}'", message, key))?);
+        }
+    }
+    Ok(FileTheme { styles: Rc::new(styles) })
+}
+
+// "red", 208 or "#ff8700", or a table like { color = "red", bold = true, dimmed = false }
+fn style(value: &Value) -> Result<Style, String> {
+    match value {
+        Value::Table(table) => {
+            if let Some(key) = table.keys().find(|key| !["color", "bold", "dimmed"].contains(&key.as_str())) {
+                return Err(format!("'{}' is unknown", key));
+            }
+            let flag = |key: &str| match table.get(key) {
+                Some(Value::Boolean(value)) => Ok(*value),
+                Some(_) => Err(format!("'{}' must be true or false", key)),
+                None => Ok(false),
+            };
+            Ok(Style {
+                forecolor: table.get("color").map(color).transpose()?,
+                is_bold: flag("bold")?,
+                is_dimmed: flag("dimmed")?,
+                ..Default::default()
+            })
+        }
+        value => Ok(color(value)?.normal()),
+    }
+}
+
+fn color(value: &Value) -> Result<Color, String> {
+    let invalid = || format!("invalid colour {}", value);
+    match value {
+        Value::Integer(index) => u8::try_from(*index).map(Color::Fixed).map_err(|_| invalid()),
+        Value::String(name) => match name.to_lowercase().as_str() {
+            "black" => Ok(Color::Black),
+            "red" => Ok(Color::Red),
+            "green" => Ok(Color::Green),
+            "yellow" => Ok(Color::Yellow),
+            "blue" => Ok(Color::Blue),
+            "purple" | "magenta" => Ok(Color::Purple),
+            "cyan" => Ok(Color::Cyan),
+            "white" => Ok(Color::White),
+            hex if hex.len() == 7 && hex.starts_with('#') && hex[1..].chars().all(|c| c.is_ascii_hexdigit()) => {
+                let rgb = u32::from_str_radix(&hex[1..], 16).map_err(|_| invalid())?;
+                Ok(Color::Rgb((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8))
+            }
+            _ => Err(invalid()),
+        },
+        _ => Err(invalid()),
+    }
+}
+
+// The style of the file, with the layout (i.e. the new line) of the default style
+fn merge(styles: &Styles, slot: &str, default: Style) -> Style {
+    match styles.get(slot) {
+        Some(style) => Style {
+            newline: default.newline,
+            ..*style
+        },
+        None => default,
+    }
+}
+
+impl Theme for FileTheme {
+    fn request(&self) -> Box<dyn RequestTheme> {
+        Box::new(FileRequestTheme { styles: self.styles.clone() })
+    }
+    fn response(&self) -> Box<dyn ResponseTheme> {
+        Box::new(FileResponseTheme { styles: self.styles.clone() })
+    }
+}
+
+impl RequestTheme for FileRequestTheme {
+    fn as_header(&self) -> &dyn HeaderTheme {
+        self
+    }
+    fn as_direction(&self) -> &dyn DirectionTheme {
+        self
+    }
+    fn as_data(&self) -> &dyn DataTheme {
+        self
+    }
+    fn primary(&self) -> Style {
+        merge(&self.styles, "request.primary", DefaultRequestTheme {}.primary())
+    }
+    fn secondary(&self) -> Style {
+        merge(&self.styles, "request.secondary", DefaultRequestTheme {}.secondary())
+    }
+    fn method(&self) -> Style {
+        merge(&self.styles, "request.method", DefaultRequestTheme {}.method())
+    }
+    fn url(&self) -> Style {
+        merge(&self.styles, "request.url", DefaultRequestTheme {}.url())
+    }
+}
+impl HeaderTheme for FileRequestTheme {
+    fn header_name(&self, standard: bool) -> Style {
+        header_name(&self.styles, standard, crate::ifelse!(standard, self.primary(), self.secondary()))
+    }
+    fn header_value(&self, standard: bool) -> Style {
+        header_value(&self.styles, standard, DefaultRequestTheme {}.header_value(standard))
+    }
+}
+impl DirectionTheme for FileRequestTheme {
+    fn direction(&self, standard: bool) -> Style {
+        crate::ifelse!(standard, self.primary(), self.secondary())
+    }
+}
+impl DataTheme for FileRequestTheme {
+    fn data_key(&self) -> Style {
+        data(&self.styles).data_key()
+    }
+    fn data_string(&self) -> Style {
+        data(&self.styles).data_string()
+    }
+    fn data_number(&self) -> Style {
+        data(&self.styles).data_number()
+    }
+    fn data_bool(&self) -> Style {
+        data(&self.styles).data_bool()
+    }
+    fn data_null(&self) -> Style {
+        data(&self.styles).data_null()
+    }
+    fn data_bracket(&self) -> Style {
+        data(&self.styles).data_bracket()
+    }
+}
+
+impl ResponseTheme for FileResponseTheme {
+    fn as_header(&self) -> &dyn HeaderTheme {
+        self
+    }
+    fn as_direction(&self) -> &dyn DirectionTheme {
+        self
+    }
+    fn as_markup(&self) -> &dyn MarkupTheme {
+        self
+    }
+    fn as_data(&self) -> &dyn DataTheme {
+        self
+    }
+    fn primary(&self) -> Style {
+        merge(&self.styles, "response.primary", DefaultReponseTheme {}.primary())
+    }
+    fn secondary(&self) -> Style {
+        merge(&self.styles, "response.secondary", DefaultReponseTheme {}.secondary())
+    }
+    fn version(&self) -> Style {
+        merge(&self.styles, "response.version", DefaultReponseTheme {}.version())
+    }
+    fn status(&self) -> Style {
+        merge(&self.styles, "response.status", DefaultReponseTheme {}.status())
+    }
+    fn status_success(&self) -> Style {
+        self.status_class("response.status_success", DefaultReponseTheme {}.status_success())
+    }
+    fn status_redirection(&self) -> Style {
+        self.status_class("response.status_redirection", DefaultReponseTheme {}.status_redirection())
+    }
+    fn status_client_error(&self) -> Style {
+        self.status_class("response.status_client_error", DefaultReponseTheme {}.status_client_error())
+    }
+    fn status_server_error(&self) -> Style {
+        self.status_class("response.status_server_error", DefaultReponseTheme {}.status_server_error())
+    }
+    fn timing_label(&self) -> Style {
+        merge(&self.styles, "response.timing_label", self.secondary())
+    }
+    fn timing_value(&self) -> Style {
+        merge(&self.styles, "response.timing_value", DefaultReponseTheme {}.timing_value())
+    }
+    fn tls_label(&self) -> Style {
+        merge(&self.styles, "response.tls_label", self.secondary())
+    }
+    fn tls_value(&self) -> Style {
+        merge(&self.styles, "response.tls_value", DefaultReponseTheme {}.tls_value())
+    }
+    fn tls_warning(&self) -> Style {
+        merge(&self.styles, "response.tls_warning", DefaultReponseTheme {}.tls_warning())
+    }
+}
+impl FileResponseTheme {
+    // The status of a theme replaces the default style of each class of status
+    fn status_class(&self, slot: &str, default: Style) -> Style {
+        merge(&self.styles, slot, merge(&self.styles, "response.status", default))
+    }
+}
+impl HeaderTheme for FileResponseTheme {
+    fn header_name(&self, standard: bool) -> Style {
+        header_name(&self.styles, standard, crate::ifelse!(standard, self.primary(), self.secondary()))
+    }
+    fn header_value(&self, standard: bool) -> Style {
+        header_value(&self.styles, standard, DefaultReponseTheme {}.header_value(standard))
+    }
+}
+impl DirectionTheme for FileResponseTheme {
+    fn direction(&self, standard: bool) -> Style {
+        crate::ifelse!(standard, self.primary(), self.secondary())
+    }
+}
+impl DataTheme for FileResponseTheme {
+    fn data_key(&self) -> Style {
+        data(&self.styles).data_key()
+    }
+    fn data_string(&self) -> Style {
+        data(&self.styles).data_string()
+    }
+    fn data_number(&self) -> Style {
+        data(&self.styles).data_number()
+    }
+    fn data_bool(&self) -> Style {
+        data(&self.styles).data_bool()
+    }
+    fn data_null(&self) -> Style {
+        data(&self.styles).data_null()
+    }
+    fn data_bracket(&self) -> Style {
+        data(&self.styles).data_bracket()
+    }
+}
+impl MarkupTheme for FileResponseTheme {
+    fn markup_tag(&self) -> Style {
+        merge(&self.styles, "markup.tag", DefaultReponseTheme {}.markup_tag())
+    }
+    fn markup_attribute(&self) -> Style {
+        merge(&self.styles, "markup.attribute", DefaultReponseTheme {}.markup_attribute())
+    }
+    fn markup_value(&self) -> Style {
+        merge(&self.styles, "markup.value", DefaultReponseTheme {}.markup_value())
+    }
+    fn markup_comment(&self) -> Style {
+        merge(&self.styles, "markup.comment", DefaultReponseTheme {}.markup_comment())
+    }
+}
+
+fn header_name(styles: &Styles, standard: bool, default: Style) -> Style {
+    merge(styles, crate::ifelse!(standard, "header.name", "header.custom_name"), default)
+}
+
+fn header_value(styles: &Styles, standard: bool, default: Style) -> Style {
+    merge(styles, crate::ifelse!(standard, "header.value", "header.custom_value"), default)
+}
+
+// The request and the response data have the same colours
+struct FileDataTheme<'a> {
+    styles: &'a Styles,
+}
+
+fn data(styles: &Styles) -> FileDataTheme<'_> {
+    FileDataTheme { styles }
+}
+
+impl<'a> FileDataTheme<'a> {
+    fn data_key(&self) -> Style {
+        merge(self.styles, "json.key", DefaultReponseTheme {}.data_key())
+    }
+    fn data_string(&self) -> Style {
+        merge(self.styles, "json.string", DefaultReponseTheme {}.data_string())
+    }
+    fn data_number(&self) -> Style {
+        merge(self.styles, "json.number", DefaultReponseTheme {}.data_number())
+    }
+    fn data_bool(&self) -> Style {
+        merge(self.styles, "json.bool", DefaultReponseTheme {}.data_bool())
+    }
+    fn data_null(&self) -> Style {
+        merge(self.styles, "json.null", DefaultReponseTheme {}.data_null())
+    }
+    fn data_bracket(&self) -> Style {
+        merge(self.styles, "json.bracket", DefaultReponseTheme {}.data_bracket())
+    }
+}
+
+// UNIT TESTS /////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{load, parse};
+    use crate::shell::os::OsDirs;
+    use crate::theme::error::Error;
+    use crate::theme::style::{Color, Style};
+    use crate::theme::Theme;
+    use std::path::PathBuf;
+    use std::{env, fs, process};
+
+    struct TestOsDirs(Option<PathBuf>);
+
+    impl OsDirs for TestOsDirs {
+        fn app_path(&self, filename: &str) -> Option<PathBuf> {
+            self.app_config_directory().map(|path| path.join(filename))
+        }
+        fn app_config_directory(&self) -> Option<PathBuf> {
+            self.config_directory().map(|path| path.join("rh-test-theme"))
+        }
+        fn config_directory(&self) -> Option<PathBuf> {
+            self.0.clone()
+        }
+    }
+
+    #[test]
+    fn colors() {
+        let theme = parse(
+            r##"
+            [json]
+            key = "magenta"
+            string = 28
+            number = "#FF8700"
+            null = { color = "cyan", bold = true }
+            bool = { dimmed = true }
+            "##,
+        )
+        .unwrap();
+        let data = theme.response();
+        let data = data.as_data();
+        assert_eq!(data.data_key(), Color::Purple.normal());
+        assert_eq!(data.data_string(), Color::Fixed(28).normal());
+        assert_eq!(data.data_number(), Color::Rgb(255, 135, 0).normal());
+        assert_eq!(data.data_null(), Color::Cyan.bold());
+        assert_eq!(
+            data.data_bool(),
+            Style {
+                is_dimmed: true,
+                ..Default::default()
+            }
+        );
+        // Same colours for the request data
+        assert_eq!(theme.request().as_data().data_number(), Color::Rgb(255, 135, 0).normal());
+    }
+
+    #[test]
+    fn default_styles() {
+        let theme = parse("[response]\nstatus = \"blue\"").unwrap();
+        let response = theme.response();
+        assert_eq!(response.status(), Color::Blue.normal_newline());
+        assert_eq!(response.status_client_error(), Color::Blue.normal_newline());
+        assert_eq!(response.version(), Color::Green.normal());
+        assert_eq!(response.as_data().data_key(), Color::Blue.normal());
+    }
+
+    #[test]
+    fn status_classes() {
+        let theme = parse("[response]\nstatus_server_error = 160").unwrap();
+        let response = theme.response();
+        assert_eq!(response.status_server_error(), Color::Fixed(160).normal_newline());
+        assert_eq!(response.status_client_error(), Color::Yellow.bold_newline());
+        assert_eq!(response.status(), Color::Green.bold_newline());
+
+        let theme = parse("[response]\nstatus = \"blue\"\nstatus_success = \"green\"").unwrap();
+        let response = theme.response();
+        assert_eq!(response.status_success(), Color::Green.normal_newline());
+        assert_eq!(response.status_redirection(), Color::Blue.normal_newline());
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(parse("[json]\nkey = \"pink\"").err().unwrap(), "invalid colour \"pink\" for 'json.key'");
+        assert_eq!(parse("[json]\nkey = 256").err().unwrap(), "invalid colour 256 for 'json.key'");
+        assert_eq!(parse("[json]\nkey = \"#12345\"").err().unwrap(), "invalid colour \"#12345\" for 'json.key'");
+        assert_eq!(parse("[json]\nkey = \"#+12345\"").err().unwrap(), "invalid colour \"#+12345\" for 'json.key'");
+        assert_eq!(parse("[json]\nkeys = \"red\"").err().unwrap(), "'json.keys' is unknown");
+        assert_eq!(parse("[json]\nkey = { colour = \"red\" }").err().unwrap(), "'colour' is unknown for 'json.key'");
+        assert_eq!(parse("json = 1").err().unwrap(), "'json' must be a table");
+        assert!(parse("[json").is_err());
+    }
+
+    #[test]
+    fn from_file() {
+        let config_directory = env::temp_dir().join(format!("rh-theme-{}", process::id()));
+        let os_dirs = TestOsDirs(Some(config_directory.clone()));
+        let directory = os_dirs.app_config_directory().unwrap().join("themes");
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(directory.join("light.toml"), "[json]\nkey = 18\n").unwrap();
+        let theme = load(&os_dirs, "light").unwrap();
+        assert_eq!(theme.response().as_data().data_key(), Color::Fixed(18).normal());
+
+        assert!(matches!(load(&os_dirs, "missing"), Err(Error::NotFound(name, _)) if name == "missing"));
+        assert!(matches!(load(&TestOsDirs(None), "light"), Err(Error::NoConfigDirectory)));
+        fs::remove_dir_all(config_directory).unwrap();
+    }
+}
@@ -1,8 +1,24 @@
 pub(crate) mod default;
+pub(crate) mod error;
+pub(crate) mod file;
 pub(crate) mod style;
 
+use crate::shell::os::OsDirs;
+use default::DefaultTheme;
+use error::Error;
 use style::Style;
 
+pub const DEFAULT_THEME_NAME: &str = "default";
+
+// The default theme or a theme file
+pub fn load<OD: OsDirs>(os_dirs: &OD, name: &str) -> Result<Box<dyn Theme>, Error> {
+    if name == DEFAULT_THEME_NAME {
+        Ok(Box::new(DefaultTheme::new()))
+    } else {
+        Ok(Box::new(file::load(os_dirs, name)?))
+    }
+}
+
 pub trait Theme {
     fn request(&self) -> Box<dyn RequestTheme>;
     fn response(&self) -> Box<dyn ResponseTheme>;
@@ -17,22 +33,50 @@ pub trait HeaderTheme {
     fn header_value(&self, standard: bool) -> Style;
 }
 
-pub trait RequestTheme: HeaderTheme + DirectionTheme {
+pub trait MarkupTheme {
+    fn markup_tag(&self) -> Style;
+    fn markup_attribute(&self) -> Style;
+    fn markup_value(&self) -> Style;
+    fn markup_comment(&self) -> Style;
+}
+
+pub trait DataTheme {
+    fn data_key(&self) -> Style;
+    fn data_string(&self) -> Style;
+    fn data_number(&self) -> Style;
+    fn data_bool(&self) -> Style;
+    fn data_null(&self) -> Style;
+    fn data_bracket(&self) -> Style;
+}
+
+pub trait RequestTheme: HeaderTheme + DirectionTheme + DataTheme {
     fn as_header(&self) -> &dyn HeaderTheme;
     fn as_direction(&self) -> &dyn DirectionTheme;
+    fn as_data(&self) -> &dyn DataTheme;
     fn primary(&self) -> Style;
     fn secondary(&self) -> Style;
     fn method(&self) -> Style;
     fn url(&self) -> Style;
 }
 
-pub trait ResponseTheme: HeaderTheme + DirectionTheme {
+pub trait ResponseTheme: HeaderTheme + DirectionTheme + MarkupTheme + DataTheme {
     fn as_header(&self) -> &dyn HeaderTheme;
     fn as_direction(&self) -> &dyn DirectionTheme;
+    fn as_markup(&self) -> &dyn MarkupTheme;
+    fn as_data(&self) -> &dyn DataTheme;
     fn primary(&self) -> Style;
     fn secondary(&self) -> Style;
     fn version(&self) -> Style;
     fn status(&self) -> Style;
+    fn status_success(&self) -> Style;
+    fn status_redirection(&self) -> Style;
+    fn status_client_error(&self) -> Style;
+    fn status_server_error(&self) -> Style;
+    fn timing_label(&self) -> Style;
+    fn timing_value(&self) -> Style;
+    fn tls_label(&self) -> Style;
+    fn tls_value(&self) -> Style;
+    fn tls_warning(&self) -> Style;
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
-#[derive(Default)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+#[derive(Clone, Copy, Default)]
 pub struct Style {
     pub forecolor: Option<Color>,
     pub backcolor: Option<Color>,
@@ -8,6 +9,7 @@ pub struct Style {
 }
 
 #[allow(dead_code)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
 #[derive(Clone, Copy)]
 pub enum Color {
     Black,
@@ -18,6 +20,10 @@ pub enum Color {
     Purple,
     Cyan,
     White,
+    // One of the 256 colours of most terminals
+    Fixed(u8),
+    // True colour
+    Rgb(u8, u8, u8),
 }
 
 impl Style {
@@ -12,7 +12,7 @@ fn shell<'a, OD: OsDirs>(os_dirs: &'a OD) -> Shell<'a, OD, Vec<u8>, Vec<u8>> {
 
 #[test]
 fn no_args() {
-    let os_dirs = DefaultOsDirs::default();
+    let os_dirs = DefaultOsDirs;
     let mut shell = shell(&os_dirs);
 
     let mut args = rh_test::args![];
@@ -29,7 +29,7 @@ fn get_localhost() {
     });
     let url = server.url("/");
 
-    let os_dirs = DefaultOsDirs::default();
+    let os_dirs = DefaultOsDirs;
     let mut shell = shell(&os_dirs);
 
     let mut args = rh_test::args![url];